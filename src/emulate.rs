@@ -2,18 +2,35 @@
 mod macros;
 pub mod compress;
 pub mod profile;
+#[cfg(feature = "emulation-session")]
+pub mod session;
 
-use profile::{chrome::*, firefox::*, okhttp::*, opera::*, safari::*};
+use std::{borrow::Cow, error::Error, fmt};
+
+use profile::{
+    cfnetwork::*, chrome::*, curl::*, dotnet::*, firefox::*, go::*, java::*, node::*, okhttp::*,
+    opera::*, python::*, safari::*,
+};
 #[cfg(feature = "emulation-serde")]
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
+use wreq::header::{CACHE_CONTROL, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+
+use crate::rand::Rng;
 
 define_enum!(
     /// Selects which client profile the request should look like.
     ///
     /// This controls the built-in TLS, HTTP/2, and header presets used for the
     /// request. Variants cover browser-style profiles as well as other clients,
-    /// such as OkHttp.
+    /// such as OkHttp, CFNetwork, curl, Go, Python, Node, Java and .NET.
+    ///
+    /// The OpenSSL-based curl profiles and the Python profiles are approximations.
+    /// BoringSSL lacks some of the cipher suites and extensions OpenSSL sends, so
+    /// their JA3/JA4 can never match the real client. The Go profiles report
+    /// `Go-http-client/2.0` whenever HTTP/2 is enabled, which assumes the
+    /// connection negotiates h2 over TLS; real Go sends `Go-http-client/1.1` on
+    /// HTTP/1 connections, including plain `http://`.
     dispatch,
     Profile, Chrome100,
     Emulation,
@@ -82,6 +99,17 @@ define_enum!(
     Edge147 => ("edge_147", edge147::emulation),
     Edge148 => ("edge_148", edge148::emulation),
 
+    // Brave versions
+    Brave146 => ("brave_146", brave146::emulation),
+    Brave147 => ("brave_147", brave147::emulation),
+    Brave148 => ("brave_148", brave148::emulation),
+    Brave149 => ("brave_149", brave149::emulation),
+
+    // Vivaldi versions
+    Vivaldi7_7 => ("vivaldi_7.7", vivaldi7_7::emulation),
+    Vivaldi7_8 => ("vivaldi_7.8", vivaldi7_8::emulation),
+    Vivaldi7_9 => ("vivaldi_7.9", vivaldi7_9::emulation),
+
     // Opera versions
     Opera116 => ("opera_116", opera116::emulation),
     Opera117 => ("opera_117", opera117::emulation),
@@ -100,12 +128,19 @@ define_enum!(
     Opera130 => ("opera_130", opera130::emulation),
     Opera131 => ("opera_131", opera131::emulation),
 
+    // Samsung Internet versions
+    SamsungInternet27 => ("samsung_internet_27", samsung27::emulation),
+    SamsungInternet28 => ("samsung_internet_28", samsung28::emulation),
+    SamsungInternet29 => ("samsung_internet_29", samsung29::emulation),
+
     // Firefox versions
     Firefox109 => ("firefox_109", ff109::emulation),
     Firefox117 => ("firefox_117", ff117::emulation),
     Firefox128 => ("firefox_128", ff128::emulation),
     Firefox133 => ("firefox_133", ff133::emulation),
     Firefox135 => ("firefox_135", ff135::emulation),
+    // Same as `Firefox135`/`Firefox136` with `private(true)` or
+    // `Platform::Android`, kept for existing users.
     FirefoxPrivate135 => ("firefox_private_135", ff_private_135::emulation),
     FirefoxAndroid135 => ("firefox_android_135", ff_android_135::emulation),
     Firefox136 => ("firefox_136", ff136::emulation),
@@ -121,6 +156,14 @@ define_enum!(
     Firefox149 => ("firefox_149", ff149::emulation),
     Firefox150 => ("firefox_150", ff150::emulation),
     Firefox151 => ("firefox_151", ff151::emulation),
+    FirefoxEsr115 => ("firefox_esr_115", ff_esr115::emulation),
+    FirefoxEsr128 => ("firefox_esr_128", ff_esr128::emulation),
+    FirefoxEsr140 => ("firefox_esr_140", ff_esr140::emulation),
+
+    // Tor Browser versions
+    TorBrowser13_5 => ("tor_browser_13.5", tor13_5::emulation),
+    TorBrowser14_5 => ("tor_browser_14.5", tor14_5::emulation),
+    TorBrowser15 => ("tor_browser_15", tor15::emulation),
 
     // Safari versions
     SafariIos17_2 => ("safari_ios_17.2", safari_ios_17_2::emulation),
@@ -153,6 +196,13 @@ define_enum!(
     SafariIos26 => ("safari_ios_26", safari_ios_26::emulation),
     SafariIos26_2 => ("safari_ios_26.2", safari_ios_26_2::emulation),
 
+    // WebView versions
+    WebViewIos26 => ("webview_ios_26", webview_ios_26::emulation),
+    InstagramIos26 => ("instagram_ios_26", instagram_ios_26::emulation),
+    FacebookIos26 => ("facebook_ios_26", facebook_ios_26::emulation),
+    WebViewAndroid148 => ("webview_android_148", webview_android_148::emulation),
+    WebViewAndroid149 => ("webview_android_149", webview_android_149::emulation),
+
     // OkHttp versions
     OkHttp3_9 => ("okhttp_3.9", okhttp3_9::emulation),
     OkHttp3_11 => ("okhttp_3.11", okhttp3_11::emulation),
@@ -161,7 +211,39 @@ define_enum!(
     OkHttp4_9 => ("okhttp_4.9", okhttp4_9::emulation),
     OkHttp4_10 => ("okhttp_4.10", okhttp4_10::emulation),
     OkHttp4_12 => ("okhttp_4.12", okhttp4_12::emulation),
-    OkHttp5 => ("okhttp_5", okhttp5::emulation)
+    OkHttp5 => ("okhttp_5", okhttp5::emulation),
+
+    // CFNetwork versions
+    CFNetwork1490 => ("cfnetwork_1490", cfnetwork1490::emulation),
+    CFNetwork3826 => ("cfnetwork_3826", cfnetwork3826::emulation),
+    CFNetwork3860 => ("cfnetwork_3860", cfnetwork3860::emulation),
+
+    // curl versions
+    Curl8_5 => ("curl_8.5", curl8_5::emulation),
+    Curl8_14 => ("curl_8.14", curl8_14::emulation),
+    CurlBoringSsl8_14 => ("curl_boringssl_8.14", curl_boringssl_8_14::emulation),
+
+    // Go versions
+    Go1_23 => ("go_1.23", go1_23::emulation),
+    Go1_24 => ("go_1.24", go1_24::emulation),
+
+    // Python versions
+    PythonRequests2_32 => ("python_requests_2.32", python_requests_2_32::emulation),
+    Httpx0_28 => ("httpx_0.28", httpx0_28::emulation),
+
+    // Node versions
+    Node22 => ("node_22", node22::emulation),
+    Node24 => ("node_24", node24::emulation),
+
+    // Java versions
+    JavaHttpClient11 => ("java_http_client_11", java11::emulation),
+    JavaHttpClient17 => ("java_http_client_17", java17::emulation),
+    JavaHttpClient21 => ("java_http_client_21", java21::emulation),
+
+    // .NET versions
+    DotNetHttpClient8 => ("dotnet_http_client_8", dotnet8::emulation),
+    DotNetHttpClient9 => ("dotnet_http_client_9", dotnet9::emulation),
+    DotNetHttpClient10 => ("dotnet_http_client_10", dotnet10::emulation)
 
 );
 
@@ -172,6 +254,141 @@ impl wreq::IntoEmulation for Profile {
     }
 }
 
+impl Profile {
+    /// Whether the profile is built on Chromium, which marks form submissions
+    /// with `cache-control: max-age=0`.
+    const fn is_chromium(&self) -> bool {
+        use Profile::*;
+        matches!(
+            self,
+            Chrome100
+                | Chrome101
+                | Chrome104
+                | Chrome105
+                | Chrome106
+                | Chrome107
+                | Chrome108
+                | Chrome109
+                | Chrome110
+                | Chrome114
+                | Chrome116
+                | Chrome117
+                | Chrome118
+                | Chrome119
+                | Chrome120
+                | Chrome123
+                | Chrome124
+                | Chrome126
+                | Chrome127
+                | Chrome128
+                | Chrome129
+                | Chrome130
+                | Chrome131
+                | Chrome132
+                | Chrome133
+                | Chrome134
+                | Chrome135
+                | Chrome136
+                | Chrome137
+                | Chrome138
+                | Chrome139
+                | Chrome140
+                | Chrome141
+                | Chrome142
+                | Chrome143
+                | Chrome144
+                | Chrome145
+                | Chrome146
+                | Chrome147
+                | Chrome148
+                | Chrome149
+                | Edge101
+                | Edge122
+                | Edge127
+                | Edge131
+                | Edge134
+                | Edge135
+                | Edge136
+                | Edge137
+                | Edge138
+                | Edge139
+                | Edge140
+                | Edge141
+                | Edge142
+                | Edge143
+                | Edge144
+                | Edge145
+                | Edge146
+                | Edge147
+                | Edge148
+                | Brave146
+                | Brave147
+                | Brave148
+                | Brave149
+                | Vivaldi7_7
+                | Vivaldi7_8
+                | Vivaldi7_9
+                | Opera116
+                | Opera117
+                | Opera118
+                | Opera119
+                | Opera120
+                | Opera121
+                | Opera122
+                | Opera123
+                | Opera124
+                | Opera125
+                | Opera126
+                | Opera127
+                | Opera128
+                | Opera129
+                | Opera130
+                | Opera131
+                | SamsungInternet27
+                | SamsungInternet28
+                | SamsungInternet29
+                | WebViewAndroid148
+                | WebViewAndroid149
+        )
+    }
+
+    /// Whether the profile is a native HTTP client rather than a browser.
+    ///
+    /// Native clients send no per-request headers of their own.
+    const fn is_native(&self) -> bool {
+        use Profile::*;
+        matches!(
+            self,
+            OkHttp3_9
+                | OkHttp3_11
+                | OkHttp3_13
+                | OkHttp3_14
+                | OkHttp4_9
+                | OkHttp4_10
+                | OkHttp4_12
+                | OkHttp5
+                | CFNetwork1490
+                | CFNetwork3826
+                | CFNetwork3860
+                | Curl8_5
+                | Curl8_14
+                | CurlBoringSsl8_14
+                | Go1_23
+                | Go1_24
+                | PythonRequests2_32
+                | Httpx0_28
+                | Node22
+                | Node24
+                | JavaHttpClient11
+                | JavaHttpClient17
+                | JavaHttpClient21
+                | DotNetHttpClient8
+                | DotNetHttpClient9
+                | DotNetHttpClient10
+        )
+    }
+}
+
 define_enum!(
     /// Selects which platform the client should look like.
     ///
@@ -205,6 +422,229 @@ impl Platform {
     }
 }
 
+define_enum!(
+    /// Selects which kind of request the default headers should describe.
+    ///
+    /// Browsers send different `accept`, `content-type`, `cache-control`,
+    /// `sec-fetch-*` and `priority` headers depending on how a request was
+    /// initiated.
+    ///
+    /// The `content-type` and `cache-control` headers describe a single request
+    /// body, so they are never sent as client-wide defaults; add them per request
+    /// with [`Emulation::request_headers`]. The `origin` header must also be set
+    /// per request. All of them are slotted into the browser position by the
+    /// header order.
+    ///
+    /// Only browser profiles are affected; native-client profiles such as
+    /// OkHttp ignore this option.
+    plain,
+    RequestKind, Navigate,
+    Navigate => "navigate",
+    FormSubmit => "form_submit",
+    JsonFetch => "json_fetch",
+    MultipartUpload => "multipart_upload"
+);
+
+impl RequestKind {
+    /// Whether the request is a top-level document navigation.
+    #[inline]
+    const fn is_navigation(&self) -> bool {
+        !matches!(self, RequestKind::JsonFetch)
+    }
+
+    /// Whether the request is an HTML form submission.
+    #[inline]
+    const fn is_form(&self) -> bool {
+        matches!(self, RequestKind::FormSubmit | RequestKind::MultipartUpload)
+    }
+
+    /// The default `content-type`, if the request kind has a fixed one.
+    ///
+    /// Multipart bodies carry a per-request boundary, so the content type is
+    /// left to the request.
+    #[inline]
+    const fn content_type(&self) -> Option<&'static str> {
+        match self {
+            RequestKind::FormSubmit => Some("application/x-www-form-urlencoded"),
+            RequestKind::JsonFetch => Some("application/json"),
+            RequestKind::Navigate | RequestKind::MultipartUpload => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn sec_fetch_site(&self) -> &'static str {
+        match self {
+            RequestKind::Navigate => "none",
+            _ => "same-origin",
+        }
+    }
+
+    #[inline]
+    const fn sec_fetch_mode(&self) -> &'static str {
+        if self.is_navigation() {
+            "navigate"
+        } else {
+            "cors"
+        }
+    }
+
+    #[inline]
+    const fn sec_fetch_dest(&self) -> &'static str {
+        if self.is_navigation() {
+            "document"
+        } else {
+            "empty"
+        }
+    }
+}
+
+/// The app a native-app profile (e.g. [`Profile::OkHttp4_12`] or
+/// [`Profile::CFNetwork3860`]) reports in its `user-agent`.
+///
+/// Native HTTP stacks are embedded in apps, which put their own name and
+/// version in front of the stack's `user-agent` token. Use an [`App`] to
+/// report your own app while keeping the profile's TLS and HTTP/2 fingerprint,
+/// instead of the third-party app some built-in profiles were captured from.
+///
+/// The profile always appends its own stack token (e.g. `okhttp/4.12.0`), so
+/// the `user-agent` can't drift from the fingerprint. To keep it that way,
+/// [`App::new`] and [`App::device_model`] reject values that would break the
+/// `user-agent` apart.
+///
+/// # Examples
+///
+/// ```
+/// use wreq_util::{App, Emulation};
+///
+/// // "Acme/2.4.1 (Android SDK 34; Pixel 8) okhttp/4.12.0"
+/// let app = App::new("Acme", "2.4.1")?
+///     .android_sdk(34)
+///     .device_model("Pixel 8")?;
+/// let emulation = Emulation::builder()
+///     .profile(Emulation::OkHttp4_12)
+///     .app(app)
+///     .build();
+/// # Ok::<(), wreq_util::InvalidApp>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct App {
+    name: Cow<'static, str>,
+    version: Cow<'static, str>,
+    android_sdk: Option<u32>,
+    device_model: Option<Cow<'static, str>>,
+    headers: HeaderMap,
+}
+
+// ===== impl App =====
+
+impl App {
+    /// Creates an [`App`] with the given name and version.
+    ///
+    /// Both must be non-empty and made of visible ASCII characters other than
+    /// `/`, `(` and `)`.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        version: impl Into<Cow<'static, str>>,
+    ) -> Result<App, InvalidApp> {
+        let is_product = |part: &str| {
+            !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_graphic() && !matches!(b, b'/' | b'(' | b')'))
+        };
+
+        let name = name.into();
+        if !is_product(&name) {
+            return Err(InvalidApp::new("name"));
+        }
+        let version = version.into();
+        if !is_product(&version) {
+            return Err(InvalidApp::new("version"));
+        }
+
+        Ok(App {
+            name,
+            version,
+            android_sdk: None,
+            device_model: None,
+            headers: HeaderMap::new(),
+        })
+    }
+
+    /// Report the Android SDK level the app runs on. Only used by Android
+    /// profiles such as OkHttp.
+    pub fn android_sdk(mut self, sdk: u32) -> App {
+        self.android_sdk = Some(sdk);
+        self
+    }
+
+    /// Report the device model the app runs on. Only used by Android profiles
+    /// such as OkHttp.
+    ///
+    /// The model must be non-empty and made of printable ASCII characters
+    /// other than `(`, `)` and `;`.
+    pub fn device_model(mut self, model: impl Into<Cow<'static, str>>) -> Result<App, InvalidApp> {
+        let model = model.into();
+        let valid = !model.is_empty()
+            && model
+                .bytes()
+                .all(|b| (b == b' ' || b.is_ascii_graphic()) && !matches!(b, b'(' | b')' | b';'));
+        if !valid {
+            return Err(InvalidApp::new("device model"));
+        }
+        self.device_model = Some(model);
+        Ok(self)
+    }
+
+    /// Send `name: value` with every request, e.g. an app-specific version or
+    /// client id header.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> App {
+        self.headers.append(name, value);
+        self
+    }
+
+    /// Renders `<name>/<version> (<details>) <token>`, leaving out the details
+    /// when there are none.
+    fn user_agent(&self, token: &str) -> HeaderValue {
+        let details = match (self.android_sdk, &self.device_model) {
+            (Some(sdk), Some(model)) => format!(" (Android SDK {sdk}; {model})"),
+            (Some(sdk), None) => format!(" (Android SDK {sdk})"),
+            (None, Some(model)) => format!(" ({model})"),
+            (None, None) => String::new(),
+        };
+        let user_agent = format!("{}/{}{details} {token}", self.name, self.version);
+        HeaderValue::try_from(user_agent).expect("app identity is validated on construction")
+    }
+
+    /// Adds the app's extra headers to `headers`.
+    fn extend_headers(&self, headers: &mut HeaderMap) {
+        for (name, value) in &self.headers {
+            headers.append(name, value.clone());
+        }
+    }
+}
+
+/// Error returned when an [`App`] identity would not fit into a `user-agent`.
+#[derive(Debug)]
+pub struct InvalidApp {
+    field: &'static str,
+}
+
+impl InvalidApp {
+    #[inline]
+    fn new(field: &'static str) -> Self {
+        InvalidApp { field }
+    }
+}
+
+impl fmt::Display for InvalidApp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid app {}", self.field)
+    }
+}
+
+impl Error for InvalidApp {}
+
 /// Represents the configuration options for emulating a client profile and platform.
 ///
 /// The `Emulation` struct allows you to configure various aspects of profile and platform
@@ -217,9 +657,17 @@ pub struct Emulation {
     profile: Profile,
 
     /// Whether to change the platform (Windows/macOS/Linux/Android/iOS) information.
+    ///
+    /// On Android, Firefox profiles also switch to Firefox for Android's TLS and
+    /// HTTP/2 settings. Chromium-based profiles only switch their headers and
+    /// keep the desktop ClientHello and HTTP/2 settings.
     #[builder(default)]
     platform: Platform,
 
+    /// Whether to change the request kind (navigation/form submit/JSON fetch/multipart upload).
+    #[builder(default)]
+    request_kind: RequestKind,
+
     /// Whether to enable HTTP/2.
     #[builder(default = true)]
     http2: bool,
@@ -227,6 +675,29 @@ pub struct Emulation {
     /// Whether to include default headers.
     #[builder(default = true)]
     headers: bool,
+
+    /// Whether to sort request headers into the profile's browser order.
+    ///
+    /// This also applies to headers added per request (e.g. `cookie`, `referer`,
+    /// `content-type`, `origin`), which are slotted where the browser sends them.
+    #[builder(default = true)]
+    header_order: bool,
+
+    /// Whether to emulate a private browsing window.
+    ///
+    /// Firefox private windows don't offer session tickets or resume sessions
+    /// with a PSK. Chrome Incognito and Safari Private Browsing look the same
+    /// on the wire as regular windows, so those profiles ignore this option.
+    #[builder(default)]
+    private: bool,
+
+    /// The app native-app profiles report in their `user-agent`.
+    ///
+    /// OkHttp profiles keep the `user-agent` they were captured with when this
+    /// is unset, and CFNetwork profiles send the bare `CFNetwork/… Darwin/…`
+    /// token.
+    #[builder(default, setter(strip_option))]
+    app: Option<App>,
 }
 
 impl Emulation {
@@ -240,8 +711,24 @@ impl Emulation {
     /// let random_emulation = Emulation::random();
     /// println!("{:?}", random_emulation);
     /// ```
+    #[inline]
     pub fn random() -> Emulation {
-        let rand = crate::rand::fast_random();
+        Self::random_with(&Rng::default())
+    }
+
+    /// Returns a random variant of the `Profile` enum, drawn from `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wreq_util::{Emulation, Rng};
+    ///
+    /// // The same seed always picks the same emulation.
+    /// let emulation = Emulation::random_with(&Rng::seeded(42));
+    /// println!("{:?}", emulation);
+    /// ```
+    pub fn random_with(rng: &Rng) -> Emulation {
+        let rand = rng.next_u64();
         Emulation::builder()
             .profile(Profile::VARIANTS[(rand as usize) % Profile::VARIANTS.len()])
             .platform(Platform::VARIANTS[((rand >> 32) as usize) % Platform::VARIANTS.len()])
@@ -266,6 +753,9 @@ impl Emulation {
     /// StatCounter requests attribution for use of its data. See:
     /// <https://creativecommons.org/licenses/by-sa/3.0/>
     ///
+    /// Brave and Vivaldi are counted as Chrome by StatCounter, so their weights
+    /// are estimates carved out of Chrome's share.
+    ///
     /// # Examples
     ///
     /// ```
@@ -274,7 +764,15 @@ impl Emulation {
     /// let random_emulation = Emulation::weighted_random();
     /// println!("{:?}", random_emulation);
     /// ```
+    #[inline]
     pub fn weighted_random() -> Emulation {
+        Self::weighted_random_with(&Rng::default())
+    }
+
+    /// Returns a market-share weighted random `Emulation`, drawn from `rng`.
+    ///
+    /// See [`Emulation::weighted_random`] for how profiles are weighted.
+    pub fn weighted_random_with(rng: &Rng) -> Emulation {
         use Platform::*;
         use Profile::*;
 
@@ -295,9 +793,14 @@ impl Emulation {
         // Safari's 14.77% is split by platform using the browser-version data:
         // iPhone 11.96% + iPad 0.44% = 12.40% mobile (-> 1240), leaving the
         // remaining 2.37% for desktop/macOS (-> 237).
+        //
+        // Brave and Vivaldi send Chrome's user agent, so StatCounter counts them
+        // as Chrome. Their shares are estimated from the vendors' published user
+        // counts (Brave ~1.00% -> 100, Vivaldi ~0.10% -> 10) and taken out of
+        // Chrome's weight (7141 - 110 = 7031).
         const CLASSES: &[Class] = &[
             Class {
-                weight: 7141,
+                weight: 7031,
                 platforms: &[Windows, MacOS, Linux, Android],
                 profiles: &[
                     Chrome149, Chrome148, Chrome147, Chrome146, Chrome145, Chrome144, Chrome143,
@@ -339,9 +842,19 @@ impl Emulation {
                 platforms: &[Windows, MacOS, Linux, Android],
                 profiles: &[Opera131, Opera130, Opera129, Opera128, Opera127, Opera126],
             },
+            Class {
+                weight: 100,
+                platforms: &[Windows, MacOS, Linux],
+                profiles: &[Brave149, Brave148, Brave147, Brave146],
+            },
+            Class {
+                weight: 10,
+                platforms: &[Windows, MacOS, Linux],
+                profiles: &[Vivaldi7_9, Vivaldi7_8, Vivaldi7_7],
+            },
         ];
 
-        let (r1, r2) = (crate::rand::fast_random(), crate::rand::fast_random());
+        let (r1, r2) = (rng.next_u64(), rng.next_u64());
         let total: u32 = CLASSES.iter().map(|c| c.weight).sum();
         let mut t = (r1 % total as u64) as u32;
         let class = CLASSES
@@ -358,6 +871,49 @@ impl Emulation {
             .platform(class.platforms[(r2 as usize) % class.platforms.len()])
             .build()
     }
+
+    /// Returns the headers the emulated profile adds to a single request of the
+    /// configured [`RequestKind`], such as the `content-type` of a JSON fetch or
+    /// Chrome's `cache-control: max-age=0` on form submissions.
+    ///
+    /// These are left out of the client's default headers so they don't leak
+    /// onto other requests, including the `GET` that follows a redirected form
+    /// submission. Pass them to the request instead:
+    ///
+    /// ```no_run
+    /// use wreq_util::{Emulation, RequestKind};
+    ///
+    /// # async fn run() -> wreq::Result<()> {
+    /// let emulation = Emulation::builder()
+    ///     .profile(Emulation::Chrome147)
+    ///     .request_kind(RequestKind::FormSubmit)
+    ///     .build();
+    /// let headers = emulation.request_headers();
+    /// let client = wreq::Client::builder().emulation(emulation).build()?;
+    ///
+    /// client
+    ///     .post("https://example.com/login")
+    ///     .headers(headers)
+    ///     .body("user=alice")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if !self.headers || self.profile.is_native() {
+            return headers;
+        }
+
+        if let Some(content_type) = self.request_kind.content_type() {
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        }
+        if self.profile.is_chromium() && self.request_kind.is_form() {
+            headers.insert(CACHE_CONTROL, HeaderValue::from_static("max-age=0"));
+        }
+        headers
+    }
 }
 
 impl wreq::IntoEmulation for Emulation {
@@ -94,11 +94,47 @@ macro_rules! header_chrome_sec_ch_ua {
 }
 
 macro_rules! header_chrome_sec_fetch {
-    ($headers:expr) => {
-        $headers.insert("sec-fetch-site", HeaderValue::from_static("none"));
-        $headers.insert("sec-fetch-mode", HeaderValue::from_static("navigate"));
-        $headers.insert("sec-fetch-user", HeaderValue::from_static("?1"));
-        $headers.insert("sec-fetch-dest", HeaderValue::from_static("document"));
+    ($headers:expr, $request_kind:expr) => {
+        $headers.insert(
+            "sec-fetch-site",
+            HeaderValue::from_static($request_kind.sec_fetch_site()),
+        );
+        $headers.insert(
+            "sec-fetch-mode",
+            HeaderValue::from_static($request_kind.sec_fetch_mode()),
+        );
+        if $request_kind.is_navigation() {
+            $headers.insert("sec-fetch-user", HeaderValue::from_static("?1"));
+        }
+        $headers.insert(
+            "sec-fetch-dest",
+            HeaderValue::from_static($request_kind.sec_fetch_dest()),
+        );
+    };
+}
+
+macro_rules! header_chrome_priority {
+    ($headers:expr, $request_kind:expr) => {
+        let priority = if $request_kind.is_navigation() {
+            "u=0, i"
+        } else {
+            "u=1, i"
+        };
+        $headers.insert(
+            HeaderName::from_static("priority"),
+            HeaderValue::from_static(priority),
+        );
+    };
+}
+
+macro_rules! header_chrome_upgrade_insecure_requests {
+    ($headers:expr, $request_kind:expr) => {
+        if $request_kind.is_navigation() {
+            $headers.insert(
+                HeaderName::from_static("upgrade-insecure-requests"),
+                HeaderValue::from_static("1"),
+            );
+        }
     };
 }
 
@@ -109,8 +145,12 @@ macro_rules! header_chrome_ua {
 }
 
 macro_rules! header_chrome_accept {
-    ($headers:expr) => {
-        $headers.insert(ACCEPT, HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.7"));
+    ($headers:expr, $request_kind:expr) => {
+        if $request_kind.is_navigation() {
+            $headers.insert(ACCEPT, HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.7"));
+        } else {
+            $headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+        }
     };
 }
 
@@ -134,22 +174,28 @@ macro_rules! header_chrome_accept_encoding {
 }
 
 macro_rules! header_firefox_sec_fetch {
-    ($headers:expr) => {
-        $headers.insert("sec-fetch-dest", HeaderValue::from_static("document"));
-        $headers.insert("sec-fetch-mode", HeaderValue::from_static("navigate"));
-        $headers.insert("sec-fetch-site", HeaderValue::from_static("none"));
-        $headers.insert("sec-fetch-user", HeaderValue::from_static("?1"));
+    ($headers:expr, $request_kind:expr) => {
+        $headers.insert(
+            "sec-fetch-dest",
+            HeaderValue::from_static($request_kind.sec_fetch_dest()),
+        );
+        $headers.insert(
+            "sec-fetch-mode",
+            HeaderValue::from_static($request_kind.sec_fetch_mode()),
+        );
+        $headers.insert(
+            "sec-fetch-site",
+            HeaderValue::from_static($request_kind.sec_fetch_site()),
+        );
+        if $request_kind.is_navigation() {
+            $headers.insert("sec-fetch-user", HeaderValue::from_static("?1"));
+        }
     };
 }
 
 macro_rules! header_firefox_accept {
-    ($headers:expr) => {
-        $headers.insert(
-            ACCEPT,
-            HeaderValue::from_static(
-                "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
-            ),
-        );
+    ($headers:expr, $request_kind:expr) => {
+        header_firefox_accept!(@accept $headers, $request_kind);
         $headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.5"));
         #[cfg(feature = "emulation-compression")]
         $headers.insert(
@@ -157,13 +203,8 @@ macro_rules! header_firefox_accept {
             HeaderValue::from_static("gzip, deflate, br"),
         );
     };
-    (zstd, $headers:expr) => {
-        $headers.insert(
-            ACCEPT,
-            HeaderValue::from_static(
-                "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
-            ),
-        );
+    (zstd, $headers:expr, $request_kind:expr) => {
+        header_firefox_accept!(@accept $headers, $request_kind);
         $headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.5"));
         #[cfg(feature = "emulation-compression")]
         $headers.insert(
@@ -171,6 +212,18 @@ macro_rules! header_firefox_accept {
             HeaderValue::from_static("gzip, deflate, br, zstd"),
         );
     };
+    (@accept $headers:expr, $request_kind:expr) => {
+        if $request_kind.is_navigation() {
+            $headers.insert(
+                ACCEPT,
+                HeaderValue::from_static(
+                    "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
+                ),
+            );
+        } else {
+            $headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+        }
+    };
 }
 
 macro_rules! header_firefox_ua {
@@ -185,36 +238,83 @@ macro_rules! join {
     };
 }
 
+macro_rules! desktop_only {
+    () => {
+        false
+    };
+    ($desktop_only:literal) => {
+        $desktop_only
+    };
+}
+
 macro_rules! standard_mod_generator {
-    ($mod_name:ident, $tls_options:expr, $http2_options:expr, $headers:expr) => {
+    (
+        $mod_name:ident,
+        $tls_options:expr,
+        $http2_options:expr,
+        $header_order:expr,
+        $headers:expr,
+        $desktop_only:expr
+    ) => {
         pub(crate) mod $mod_name {
             use super::*;
 
             #[inline]
             pub fn emulation(emulation: Emulation) -> wreq::Emulation {
-                build_emulation(emulation.http2, ($headers)(&emulation))
+                build_emulation(
+                    &emulation,
+                    ($headers)(&emulation),
+                    emulation
+                        .header_order
+                        .then(|| build_orig_headers(($header_order)(emulation.request_kind))),
+                    $desktop_only,
+                )
             }
 
             pub fn build_emulation(
-                http2: bool,
+                emulation: &Emulation,
                 default_headers: Option<HeaderMap>,
+                orig_headers: Option<OrigHeaderMap>,
+                desktop_only: bool,
             ) -> wreq::Emulation {
+                let mut tls_options: TlsOptions = $tls_options;
+                let mut http2_options = emulation.http2.then(|| $http2_options);
+                browsing_mode(
+                    emulation,
+                    desktop_only,
+                    &mut tls_options,
+                    http2_options.as_mut(),
+                );
                 build_standard_emulation(
                     stringify!($mod_name),
-                    $tls_options,
-                    http2.then(|| $http2_options),
+                    tls_options,
+                    http2_options,
                     default_headers,
+                    orig_headers,
                 )
             }
         }
     };
-    ($mod_name:ident, $build_emulation:expr, $headers:expr) => {
+    (
+        $mod_name:ident,
+        $build_emulation:expr,
+        $header_order:expr,
+        $headers:expr,
+        $desktop_only:expr
+    ) => {
         pub(crate) mod $mod_name {
             use super::*;
 
             #[inline]
             pub fn emulation(emulation: Emulation) -> wreq::Emulation {
-                $build_emulation(emulation.http2, ($headers)(&emulation))
+                $build_emulation(
+                    &emulation,
+                    ($headers)(&emulation),
+                    emulation
+                        .header_order
+                        .then(|| build_orig_headers(($header_order)(emulation.request_kind))),
+                    $desktop_only,
+                )
             }
         }
     };
@@ -222,7 +322,9 @@ macro_rules! standard_mod_generator {
 
 macro_rules! fixed_headers {
     ($emulation:expr, $header_initializer:ident, $ua:expr) => {
-        $emulation.headers.then(|| $header_initializer($ua))
+        $emulation
+            .headers
+            .then(|| $header_initializer($ua, $emulation.request_kind))
     };
 }
 
@@ -240,12 +342,14 @@ macro_rules! platform_headers {
                         $other_sec_ch_ua,
                         $other_ua,
                         $emulation.platform,
+                        $emulation.request_kind,
                     ),
                 )*
                 _ => $header_initializer(
                     $default_sec_ch_ua,
                     $default_ua,
                     Platform::$default_os,
+                    $emulation.request_kind,
                 ),
             }
         })
@@ -258,9 +362,9 @@ macro_rules! firefox_platform_headers {
         $emulation.headers.then(|| {
             match $emulation.platform {
                 $(
-                    Platform::$other_os => $header_initializer($other_ua),
+                    Platform::$other_os => $header_initializer($other_ua, $emulation.request_kind),
                 )*
-                _ => $header_initializer($default_ua),
+                _ => $header_initializer($default_ua, $emulation.request_kind),
             }
         })
     }};
@@ -273,18 +377,21 @@ macro_rules! mod_generator {
         $http2_options:expr,
         $header_initializer:ident,
         [($default_os:ident, $default_sec_ch_ua:tt, $default_ua:tt) $(, ($other_os:ident, $other_sec_ch_ua:tt, $other_ua:tt))*]
+        $(, desktop_only = $desktop_only:literal)?
     ) => {
         standard_mod_generator!(
             $mod_name,
             $tls_options,
             $http2_options,
+            header_order!($header_initializer),
             |emulation: &Emulation| {
                 platform_headers!(
                     emulation,
                     $header_initializer,
                     [($default_os, $default_sec_ch_ua, $default_ua) $(, ($other_os, $other_sec_ch_ua, $other_ua))*]
                 )
-            }
+            },
+            desktop_only!($($desktop_only)?)
         );
     };
     (
@@ -293,18 +400,21 @@ macro_rules! mod_generator {
         $http2_options:expr,
         $header_initializer:ident,
         [($default_os:ident, $default_ua:tt) $(, ($other_os:ident, $other_ua:tt))*]
+        $(, desktop_only = $desktop_only:literal)?
     ) => {
         standard_mod_generator!(
             $mod_name,
             $tls_options,
             $http2_options,
+            header_order!($header_initializer),
             |emulation: &Emulation| {
                 firefox_platform_headers!(
                     emulation,
                     $header_initializer,
                     [($default_os, $default_ua) $(, ($other_os, $other_ua))*]
                 )
-            }
+            },
+            desktop_only!($($desktop_only)?)
         );
     };
     (
@@ -313,12 +423,15 @@ macro_rules! mod_generator {
         $http2_options:expr,
         $header_initializer:ident,
         $ua:expr
+        $(, desktop_only = $desktop_only:literal)?
     ) => {
         standard_mod_generator!(
             $mod_name,
             $tls_options,
             $http2_options,
-            |emulation: &Emulation| fixed_headers!(emulation, $header_initializer, $ua)
+            header_order!($header_initializer),
+            |emulation: &Emulation| fixed_headers!(emulation, $header_initializer, $ua),
+            desktop_only!($($desktop_only)?)
         );
     };
     (
@@ -326,17 +439,20 @@ macro_rules! mod_generator {
         $build_emulation:expr,
         $header_initializer:ident,
         [($default_os:ident, $default_sec_ch_ua:tt, $default_ua:tt) $(, ($other_os:ident, $other_sec_ch_ua:tt, $other_ua:tt))*]
+        $(, desktop_only = $desktop_only:literal)?
     ) => {
         standard_mod_generator!(
             $mod_name,
             $build_emulation,
+            header_order!($header_initializer),
             |emulation: &Emulation| {
                 platform_headers!(
                     emulation,
                     $header_initializer,
                     [($default_os, $default_sec_ch_ua, $default_ua) $(, ($other_os, $other_sec_ch_ua, $other_ua))*]
                 )
-            }
+            },
+            desktop_only!($($desktop_only)?)
         );
     };
     (
@@ -344,17 +460,20 @@ macro_rules! mod_generator {
         $build_emulation:expr,
         $header_initializer:ident,
         [($default_os:ident, $default_ua:tt) $(, ($other_os:ident, $other_ua:tt))*]
+        $(, desktop_only = $desktop_only:literal)?
     ) => {
         standard_mod_generator!(
             $mod_name,
             $build_emulation,
+            header_order!($header_initializer),
             |emulation: &Emulation| {
                 firefox_platform_headers!(
                     emulation,
                     $header_initializer,
                     [($default_os, $default_ua) $(, ($other_os, $other_ua))*]
                 )
-            }
+            },
+            desktop_only!($($desktop_only)?)
         );
     };
     (
@@ -362,11 +481,14 @@ macro_rules! mod_generator {
         $build_emulation:expr,
         $header_initializer:ident,
         $ua:expr
+        $(, desktop_only = $desktop_only:literal)?
     ) => {
         standard_mod_generator!(
             $mod_name,
             $build_emulation,
-            |emulation: &Emulation| fixed_headers!(emulation, $header_initializer, $ua)
+            header_order!($header_initializer),
+            |emulation: &Emulation| fixed_headers!(emulation, $header_initializer, $ua),
+            desktop_only!($($desktop_only)?)
         );
     };
 }
@@ -1,9 +1,16 @@
 //! Emulation for different browsers.
 
+pub mod cfnetwork;
 pub mod chrome;
+pub mod curl;
+pub mod dotnet;
 pub mod firefox;
+pub mod go;
+pub mod java;
+pub mod node;
 pub mod okhttp;
 pub mod opera;
+pub mod python;
 pub mod safari;
 
 use typed_builder::TypedBuilder;
@@ -11,7 +18,10 @@ use typed_builder::TypedBuilder;
 use wreq::header::ACCEPT_ENCODING;
 use wreq::{
     Group,
-    header::{ACCEPT, ACCEPT_LANGUAGE, HeaderMap, HeaderName, HeaderValue, USER_AGENT},
+    header::{
+        ACCEPT, ACCEPT_LANGUAGE, CONNECTION, HeaderMap, HeaderName, HeaderValue, OrigHeaderMap,
+        USER_AGENT,
+    },
     http2::{
         Http2Options, Priorities, Priority, PseudoId, PseudoOrder, SettingId, SettingsOrder,
         StreamDependency, StreamId,
@@ -23,15 +33,29 @@ use wreq::{
 };
 
 use super::{
-    Emulation, Platform,
+    Emulation, Platform, RequestKind,
     compress::{BrotliCompressor, ZlibCompressor, ZstdCompressor},
 };
 
+/// Adjusts a profile's options for private browsing and mobile builds.
+///
+/// Chrome, Opera and Safari send the same handshake and HTTP/2 settings in
+/// private windows and on mobile, so this default leaves them untouched.
+/// Firefox shadows it with its own hook.
+fn browsing_mode(
+    _: &Emulation,
+    _desktop_only: bool,
+    _: &mut TlsOptions,
+    _: Option<&mut Http2Options>,
+) {
+}
+
 fn build_standard_emulation(
     group: &'static str,
     tls_options: TlsOptions,
     http2_options: Option<Http2Options>,
     default_headers: Option<HeaderMap>,
+    orig_headers: Option<OrigHeaderMap>,
 ) -> wreq::Emulation {
     let mut builder = wreq::Emulation::builder().tls_options(tls_options);
 
@@ -43,5 +67,23 @@ fn build_standard_emulation(
         builder = builder.headers(headers);
     }
 
+    if let Some(orig_headers) = orig_headers {
+        builder = builder.orig_headers(orig_headers);
+    }
+
     builder.build(Group::new(group))
 }
+
+/// Builds an [`OrigHeaderMap`] from an ordered header template.
+///
+/// The template lists every header the browser may send, in the order it sends
+/// them, including request-specific headers such as `cookie`, `referer`,
+/// `content-type` and `origin`. Headers that are absent from a request are
+/// skipped, and headers that are not in the template are appended at the end.
+fn build_orig_headers(header_order: &'static [&'static str]) -> OrigHeaderMap {
+    let mut orig_headers = OrigHeaderMap::with_capacity(header_order.len());
+    for name in header_order {
+        orig_headers.insert(*name);
+    }
+    orig_headers
+}
@@ -0,0 +1,142 @@
+use super::{
+    safari::tls::{CIPHER_LIST_2, CURVES_1, CURVES_2, SIGALGS_LIST_2},
+    *,
+};
+
+/// Header order for `URLSession`, including request-specific headers.
+const HEADER_ORDER: &[&str] = &[
+    "host",
+    "content-type",
+    "cookie",
+    "accept",
+    "user-agent",
+    "content-length",
+    "accept-language",
+    "accept-encoding",
+    "connection",
+];
+
+/// `URLSession` shares Safari's cipher suites but, unlike Safari, resumes
+/// sessions with tickets and doesn't advertise certificate compression.
+#[derive(TypedBuilder)]
+struct CFNetworkTlsConfig {
+    curves: &'static str,
+
+    #[builder(default = SIGALGS_LIST_2)]
+    sigalgs_list: &'static str,
+
+    #[builder(default = CIPHER_LIST_2)]
+    cipher_list: &'static str,
+}
+
+impl From<CFNetworkTlsConfig> for TlsOptions {
+    fn from(val: CFNetworkTlsConfig) -> Self {
+        TlsOptions::builder()
+            .grease_enabled(true)
+            .enable_ocsp_stapling(true)
+            .enable_signed_cert_timestamps(true)
+            .curves_list(val.curves)
+            .sigalgs_list(val.sigalgs_list)
+            .cipher_list(val.cipher_list)
+            .min_tls_version(TlsVersion::TLS_1_2)
+            .max_tls_version(TlsVersion::TLS_1_3)
+            .build()
+    }
+}
+
+macro_rules! mod_generator {
+    ($mod_name:ident, $curves:expr, $ua:expr) => {
+        pub(crate) mod $mod_name {
+            use super::*;
+
+            pub fn emulation(emulation: Emulation) -> wreq::Emulation {
+                build_emulation(stringify!($mod_name), emulation, $curves, $ua)
+            }
+        }
+    };
+}
+
+fn build_emulation(
+    group: &'static str,
+    emulation: Emulation,
+    curves: &'static str,
+    cfnetwork: &'static str,
+) -> wreq::Emulation {
+    let tls_options = CFNetworkTlsConfig::builder().curves(curves).build().into();
+
+    let http2_options = emulation.http2.then(|| {
+        let settings_order = SettingsOrder::builder()
+            .extend([
+                SettingId::HeaderTableSize,
+                SettingId::EnablePush,
+                SettingId::MaxConcurrentStreams,
+                SettingId::InitialWindowSize,
+                SettingId::MaxFrameSize,
+                SettingId::MaxHeaderListSize,
+                SettingId::EnableConnectProtocol,
+                SettingId::NoRfc7540Priorities,
+            ])
+            .build();
+
+        Http2Options::builder()
+            .max_concurrent_streams(100)
+            .initial_window_size(4194304)
+            .initial_connection_window_size(10485760)
+            .enable_push(false)
+            .no_rfc7540_priorities(true)
+            .headers_pseudo_order(
+                PseudoOrder::builder()
+                    .extend([
+                        PseudoId::Method,
+                        PseudoId::Scheme,
+                        PseudoId::Path,
+                        PseudoId::Authority,
+                    ])
+                    .build(),
+            )
+            .settings_order(settings_order)
+            .build()
+    });
+
+    // Apps put their own name in front of the CFNetwork token. Without one,
+    // send the token alone rather than make up an app.
+    let headers = emulation.headers.then(|| {
+        let user_agent = match &emulation.app {
+            Some(app) => app.user_agent(cfnetwork),
+            None => HeaderValue::from_static(cfnetwork),
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+        headers.insert(USER_AGENT, user_agent);
+        headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.9"));
+        #[cfg(feature = "emulation-compression")]
+        headers.insert(
+            ACCEPT_ENCODING,
+            HeaderValue::from_static("gzip, deflate, br"),
+        );
+        if let Some(app) = &emulation.app {
+            app.extend_headers(&mut headers);
+        }
+        headers
+    });
+
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(HEADER_ORDER));
+
+    build_standard_emulation(group, tls_options, http2_options, headers, orig_headers)
+}
+
+mod_generator!(cfnetwork1490, CURVES_1, "CFNetwork/1490.0.4 Darwin/23.2.0");
+
+mod_generator!(
+    cfnetwork3826,
+    CURVES_1,
+    "CFNetwork/3826.500.111.2.2 Darwin/24.4.0"
+);
+
+mod_generator!(
+    cfnetwork3860,
+    CURVES_2,
+    "CFNetwork/3860.100.1 Darwin/25.0.0"
+);
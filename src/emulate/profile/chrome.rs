@@ -2,7 +2,8 @@
 mod http2;
 #[macro_use]
 mod tls;
-mod header;
+#[macro_use]
+pub(super) mod header;
 
 use header::*;
 use tls::*;
@@ -28,7 +29,7 @@ mod_generator!(
         (
             Android,
             r#""Not A;Brand";v="99", "Chromium";v="100", "Google Chrome";v="100""#,
-            "Mozilla/5.0 (X11; U; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/100.0.4896.75 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 12; Pixel 6) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/100.0.4896.75 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -61,7 +62,7 @@ mod_generator!(
         (
             Android,
             r#""Not A;Brand";v="99", "Chromium";v="101", "Google Chrome";v="101""#,
-            "Mozilla/5.0 (X11; U; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/101.0.4951.67 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 12; Pixel 6) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/101.0.4951.67 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -129,7 +130,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="104", " Not A;Brand";v="99", "Google Chrome";v="104""#,
-            "Mozilla/5.0 (X11; U; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/104.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 12; Pixel 6) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/104.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -163,7 +164,7 @@ mod_generator!(
         (
             Android,
             r#""Google Chrome";v="105", "Not)A;Brand";v="8", "Chromium";v="105""#,
-            "Mozilla/5.0 (X11; U; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/105.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 12; Pixel 6) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/105.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -197,7 +198,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="106", "Google Chrome";v="106", "Not;A=Brand";v="99""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/106.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/106.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -230,7 +231,7 @@ mod_generator!(
         (
             Android,
             r#""Google Chrome";v="107", "Chromium";v="107", "Not=A?Brand";v="24""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/107.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/107.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -263,7 +264,7 @@ mod_generator!(
         (
             Android,
             r#""Not?A_Brand";v="8", "Chromium";v="108", "Google Chrome";v="108""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/108.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/108.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -296,7 +297,7 @@ mod_generator!(
         (
             Android,
             r#""Not_A Brand";v="99", "Google Chrome";v="109", "Chromium";v="109""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/109.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/109.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -329,7 +330,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="110", "Not A(Brand";v="24", "Google Chrome";v="110""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/110.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/110.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -362,7 +363,7 @@ mod_generator!(
         (
             Android,
             r#""Not.A/Brand";v="8", "Chromium";v="114", "Google Chrome";v="114""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -391,7 +392,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="116", "Not)A;Brand";v="24", "Google Chrome";v="116""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -425,7 +426,7 @@ mod_generator!(
         (
             Android,
             r#""Google Chrome";v="117", "Not;A=Brand";v="8", "Chromium";v="117""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/117.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/117.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -458,7 +459,7 @@ mod_generator!(
         (
             Android,
             r#""Not_A Brand";v="8", "Chromium";v="120", "Google Chrome";v="120""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -525,7 +526,7 @@ mod_generator!(
         (
             Android,
             r#""Google Chrome";v="123", "Not:A-Brand";v="8", "Chromium";v="123""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/123.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/123.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -554,7 +555,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="118", "Google Chrome";v="118", "Not=A?Brand";v="99""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/118.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/118.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -587,7 +588,7 @@ mod_generator!(
         (
             Android,
             r#""Google Chrome";v="119", "Chromium";v="119", "Not?A_Brand";v="24""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/119.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -621,7 +622,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="124", "Google Chrome";v="124", "Not-A.Brand";v="99""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -654,7 +655,7 @@ mod_generator!(
         (
             Android,
             r#""Not/A)Brand";v="8", "Chromium";v="126", "Google Chrome";v="126""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -687,7 +688,7 @@ mod_generator!(
         (
             Android,
             r#""Not)A;Brand";v="99", "Google Chrome";v="127", "Chromium";v="127""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/127.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/127.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -754,7 +755,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="128", "Not;A=Brand";v="24", "Google Chrome";v="128""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/128.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/128.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -787,7 +788,7 @@ mod_generator!(
         (
             Android,
             r#""Google Chrome";v="129", "Not=A?Brand";v="8", "Chromium";v="129""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -820,7 +821,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="130", "Google Chrome";v="130", "Not?A_Brand";v="99""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -854,7 +855,7 @@ mod_generator!(
         (
             Android,
             r#""Google Chrome";v="131", "Chromium";v="131", "Not_A Brand";v="24""#,
-            "Mozilla/5.0 (Linux: Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -988,7 +989,7 @@ mod_generator!(
         (
             Android,
             r#""Chromium";v="134", "Not:A-Brand";v="24", "Google Chrome";v="134""#,
-            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/134.0.0.0 Safari/537.36"
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/134.0.0.0 Mobile Safari/537.36"
         ),
         (
             Windows,
@@ -1992,3 +1993,221 @@ mod_generator!(
         )
     ]
 );
+
+mod_generator!(
+    brave146,
+    v132::build_emulation,
+    header_initializer_brave,
+    [
+        (
+            MacOS,
+            r#""Chromium";v="146", "Not-A.Brand";v="24", "Brave";v="146""#,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/146.0.0.0 Safari/537.36"
+        ),
+        (
+            Linux,
+            r#""Chromium";v="146", "Not-A.Brand";v="24", "Brave";v="146""#,
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/146.0.0.0 Safari/537.36"
+        ),
+        (
+            Windows,
+            r#""Chromium";v="146", "Not-A.Brand";v="24", "Brave";v="146""#,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/146.0.0.0 Safari/537.36"
+        )
+    ]
+);
+
+mod_generator!(
+    brave147,
+    v132::build_emulation,
+    header_initializer_brave,
+    [
+        (
+            MacOS,
+            r#""Brave";v="147", "Not.A/Brand";v="8", "Chromium";v="147""#,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36"
+        ),
+        (
+            Linux,
+            r#""Brave";v="147", "Not.A/Brand";v="8", "Chromium";v="147""#,
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36"
+        ),
+        (
+            Windows,
+            r#""Brave";v="147", "Not.A/Brand";v="8", "Chromium";v="147""#,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36"
+        )
+    ]
+);
+
+mod_generator!(
+    brave148,
+    v132::build_emulation,
+    header_initializer_brave,
+    [
+        (
+            MacOS,
+            r#""Chromium";v="148", "Brave";v="148", "Not/A)Brand";v="99""#,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/148.0.0.0 Safari/537.36"
+        ),
+        (
+            Linux,
+            r#""Chromium";v="148", "Brave";v="148", "Not/A)Brand";v="99""#,
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/148.0.0.0 Safari/537.36"
+        ),
+        (
+            Windows,
+            r#""Chromium";v="148", "Brave";v="148", "Not/A)Brand";v="99""#,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/148.0.0.0 Safari/537.36"
+        )
+    ]
+);
+
+mod_generator!(
+    brave149,
+    v132::build_emulation,
+    header_initializer_brave,
+    [
+        (
+            MacOS,
+            r#""Brave";v="149", "Chromium";v="149", "Not)A;Brand";v="24""#,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/149.0.0.0 Safari/537.36"
+        ),
+        (
+            Linux,
+            r#""Brave";v="149", "Chromium";v="149", "Not)A;Brand";v="24""#,
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/149.0.0.0 Safari/537.36"
+        ),
+        (
+            Windows,
+            r#""Brave";v="149", "Chromium";v="149", "Not)A;Brand";v="24""#,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/149.0.0.0 Safari/537.36"
+        )
+    ]
+);
+
+mod_generator!(
+    vivaldi7_7,
+    v132::build_emulation,
+    header_initializer_with_zstd_priority,
+    [
+        (
+            MacOS,
+            r#""Chromium";v="142", "Not_A Brand";v="99""#,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36"
+        ),
+        (
+            Linux,
+            r#""Chromium";v="142", "Not_A Brand";v="99""#,
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36"
+        ),
+        (
+            Windows,
+            r#""Chromium";v="142", "Not_A Brand";v="99""#,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36"
+        )
+    ]
+);
+
+mod_generator!(
+    vivaldi7_8,
+    v132::build_emulation,
+    header_initializer_with_zstd_priority,
+    [
+        (
+            MacOS,
+            r#""Not(A:Brand";v="8", "Chromium";v="144""#,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/144.0.0.0 Safari/537.36"
+        ),
+        (
+            Linux,
+            r#""Not(A:Brand";v="8", "Chromium";v="144""#,
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/144.0.0.0 Safari/537.36"
+        ),
+        (
+            Windows,
+            r#""Not(A:Brand";v="8", "Chromium";v="144""#,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/144.0.0.0 Safari/537.36"
+        )
+    ]
+);
+
+mod_generator!(
+    vivaldi7_9,
+    v132::build_emulation,
+    header_initializer_with_zstd_priority,
+    [
+        (
+            MacOS,
+            r#""Chromium";v="146", "Not-A.Brand";v="24""#,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/146.0.0.0 Safari/537.36"
+        ),
+        (
+            Linux,
+            r#""Chromium";v="146", "Not-A.Brand";v="24""#,
+            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/146.0.0.0 Safari/537.36"
+        ),
+        (
+            Windows,
+            r#""Chromium";v="146", "Not-A.Brand";v="24""#,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/146.0.0.0 Safari/537.36"
+        )
+    ]
+);
+
+// Samsung Internet ships Chromium's network stack unchanged, so only the
+// brand and user agent differ from the matching Chrome release.
+mod_generator!(
+    samsung27,
+    v124::build_emulation,
+    header_initializer_with_zstd_priority,
+    [(
+        Android,
+        r#""Samsung Internet";v="27.0", "Chromium";v="125", "Not.A/Brand";v="24""#,
+        "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/27.0 Chrome/125.0.0.0 Mobile Safari/537.36"
+    )]
+);
+
+mod_generator!(
+    samsung28,
+    v124::build_emulation,
+    header_initializer_with_zstd_priority,
+    [(
+        Android,
+        r#""Chromium";v="130", "Samsung Internet";v="28.0", "Not?A_Brand";v="99""#,
+        "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/28.0 Chrome/130.0.0.0 Mobile Safari/537.36"
+    )]
+);
+
+mod_generator!(
+    samsung29,
+    v132::build_emulation,
+    header_initializer_with_zstd_priority,
+    [(
+        Android,
+        r#""Chromium";v="136", "Samsung Internet";v="29.0", "Not.A/Brand";v="99""#,
+        "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/29.0 Chrome/136.0.0.0 Mobile Safari/537.36"
+    )]
+);
+
+mod_generator!(
+    webview_android_148,
+    v132::build_emulation,
+    header_initializer_with_zstd_priority,
+    [(
+        Android,
+        r#""Chromium";v="148", "Android WebView";v="148", "Not/A)Brand";v="99""#,
+        "Mozilla/5.0 (Linux; Android 10; K; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/148.0.0.0 Mobile Safari/537.36"
+    )]
+);
+
+mod_generator!(
+    webview_android_149,
+    v132::build_emulation,
+    header_initializer_with_zstd_priority,
+    [(
+        Android,
+        r#""Android WebView";v="149", "Chromium";v="149", "Not)A;Brand";v="24""#,
+        "Mozilla/5.0 (Linux; Android 10; K; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/149.0.0.0 Mobile Safari/537.36"
+    )]
+);
@@ -1,9 +1,76 @@
 use super::*;
 
+macro_rules! header_order {
+    ($header_initializer:ident) => {
+        header_order_for
+    };
+}
+
+/// Header order for Chromium-based browser navigations, including request-specific headers.
+pub const HEADER_ORDER: &[&str] = &[
+    "host",
+    "connection",
+    "content-length",
+    "pragma",
+    "cache-control",
+    "sec-ch-ua",
+    "sec-ch-ua-mobile",
+    "sec-ch-ua-platform",
+    "origin",
+    "content-type",
+    "upgrade-insecure-requests",
+    "user-agent",
+    "accept",
+    "sec-fetch-site",
+    "sec-fetch-mode",
+    "sec-fetch-user",
+    "sec-fetch-dest",
+    "referer",
+    "accept-encoding",
+    "accept-language",
+    "cookie",
+    "priority",
+];
+
+/// Header order for Chromium-based browser `fetch()` requests, which move the
+/// client hints around the `user-agent`.
+pub const CORS_HEADER_ORDER: &[&str] = &[
+    "host",
+    "connection",
+    "content-length",
+    "pragma",
+    "cache-control",
+    "sec-ch-ua-platform",
+    "user-agent",
+    "sec-ch-ua",
+    "content-type",
+    "sec-ch-ua-mobile",
+    "accept",
+    "origin",
+    "sec-fetch-site",
+    "sec-fetch-mode",
+    "sec-fetch-dest",
+    "referer",
+    "accept-encoding",
+    "accept-language",
+    "cookie",
+    "priority",
+];
+
+#[inline]
+pub fn header_order_for(request_kind: RequestKind) -> &'static [&'static str] {
+    if request_kind.is_navigation() {
+        HEADER_ORDER
+    } else {
+        CORS_HEADER_ORDER
+    }
+}
+
 pub fn header_initializer(
     sec_ch_ua: &'static str,
     ua: &'static str,
     emulation_os: Platform,
+    request_kind: RequestKind,
 ) -> HeaderMap {
     let mut headers = HeaderMap::new();
     header_chrome_sec_ch_ua!(
@@ -12,13 +79,10 @@ pub fn header_initializer(
         emulation_os.platform(),
         emulation_os.is_mobile()
     );
-    headers.insert(
-        HeaderName::from_static("upgrade-insecure-requests"),
-        HeaderValue::from_static("1"),
-    );
+    header_chrome_upgrade_insecure_requests!(headers, request_kind);
     header_chrome_ua!(headers, ua);
-    header_chrome_accept!(headers);
-    header_chrome_sec_fetch!(headers);
+    header_chrome_accept!(headers, request_kind);
+    header_chrome_sec_fetch!(headers, request_kind);
     header_chrome_accept_encoding!(headers);
     headers
 }
@@ -27,6 +91,7 @@ pub fn header_initializer_with_zstd(
     sec_ch_ua: &'static str,
     ua: &'static str,
     emulation_os: Platform,
+    request_kind: RequestKind,
 ) -> HeaderMap {
     let mut headers = HeaderMap::new();
     header_chrome_sec_ch_ua!(
@@ -35,13 +100,10 @@ pub fn header_initializer_with_zstd(
         emulation_os.platform(),
         emulation_os.is_mobile()
     );
-    headers.insert(
-        HeaderName::from_static("upgrade-insecure-requests"),
-        HeaderValue::from_static("1"),
-    );
+    header_chrome_upgrade_insecure_requests!(headers, request_kind);
     header_chrome_ua!(headers, ua);
-    header_chrome_accept!(headers);
-    header_chrome_sec_fetch!(headers);
+    header_chrome_accept!(headers, request_kind);
+    header_chrome_sec_fetch!(headers, request_kind);
     header_chrome_accept_encoding!(zstd, headers);
     headers
 }
@@ -50,6 +112,7 @@ pub fn header_initializer_with_zstd_priority(
     sec_ch_ua: &'static str,
     ua: &'static str,
     emulation_os: Platform,
+    request_kind: RequestKind,
 ) -> HeaderMap {
     let mut headers = HeaderMap::new();
     header_chrome_sec_ch_ua!(
@@ -58,17 +121,25 @@ pub fn header_initializer_with_zstd_priority(
         emulation_os.platform(),
         emulation_os.is_mobile()
     );
-    headers.insert(
-        HeaderName::from_static("upgrade-insecure-requests"),
-        HeaderValue::from_static("1"),
-    );
+    header_chrome_upgrade_insecure_requests!(headers, request_kind);
     header_chrome_ua!(headers, ua);
-    header_chrome_accept!(headers);
-    header_chrome_sec_fetch!(headers);
+    header_chrome_accept!(headers, request_kind);
+    header_chrome_sec_fetch!(headers, request_kind);
     header_chrome_accept_encoding!(zstd, headers);
-    headers.insert(
-        HeaderName::from_static("priority"),
-        HeaderValue::from_static("u=0, i"),
-    );
+    header_chrome_priority!(headers, request_kind);
+    headers
+}
+
+/// Brave reduces `accept-language` to the preferred language only, to make it
+/// less identifying.
+pub fn header_initializer_brave(
+    sec_ch_ua: &'static str,
+    ua: &'static str,
+    emulation_os: Platform,
+    request_kind: RequestKind,
+) -> HeaderMap {
+    let mut headers =
+        header_initializer_with_zstd_priority(sec_ch_ua, ua, emulation_os, request_kind);
+    headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en"));
     headers
 }
@@ -0,0 +1,191 @@
+use super::*;
+
+const OPENSSL_CURVES_1: &str = join!(":", "X25519", "P-256", "P-521", "P-384");
+
+const OPENSSL_CURVES_2: &str = join!(":", "X25519MLKEM768", "X25519", "P-256", "P-521", "P-384");
+
+const BORINGSSL_CURVES: &str = join!(":", "X25519", "P-256", "P-384");
+
+const OPENSSL_SIGALGS_LIST: &str = join!(
+    ":",
+    "ecdsa_secp256r1_sha256",
+    "ecdsa_secp384r1_sha384",
+    "ecdsa_secp521r1_sha512",
+    "ed25519",
+    "rsa_pss_rsae_sha256",
+    "rsa_pss_rsae_sha384",
+    "rsa_pss_rsae_sha512",
+    "rsa_pkcs1_sha256",
+    "rsa_pkcs1_sha384",
+    "rsa_pkcs1_sha512"
+);
+
+const BORINGSSL_SIGALGS_LIST: &str = join!(
+    ":",
+    "ecdsa_secp256r1_sha256",
+    "rsa_pss_rsae_sha256",
+    "rsa_pkcs1_sha256",
+    "ecdsa_secp384r1_sha384",
+    "rsa_pss_rsae_sha384",
+    "rsa_pkcs1_sha384",
+    "rsa_pss_rsae_sha512",
+    "rsa_pkcs1_sha512",
+    "rsa_pkcs1_sha1"
+);
+
+/// OpenSSL's default cipher list, without the DHE suites BoringSSL doesn't
+/// implement.
+const OPENSSL_CIPHER_LIST: &str = join!(
+    ":",
+    "TLS_AES_256_GCM_SHA384",
+    "TLS_CHACHA20_POLY1305_SHA256",
+    "TLS_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA",
+    "TLS_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_RSA_WITH_AES_256_CBC_SHA256",
+    "TLS_RSA_WITH_AES_128_CBC_SHA256",
+    "TLS_RSA_WITH_AES_256_CBC_SHA",
+    "TLS_RSA_WITH_AES_128_CBC_SHA"
+);
+
+const BORINGSSL_CIPHER_LIST: &str = join!(
+    ":",
+    "TLS_AES_128_GCM_SHA256",
+    "TLS_AES_256_GCM_SHA384",
+    "TLS_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA",
+    "TLS_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_RSA_WITH_AES_128_CBC_SHA",
+    "TLS_RSA_WITH_AES_256_CBC_SHA"
+);
+
+/// Header order for curl, including request-specific headers.
+const HEADER_ORDER: &[&str] = &[
+    "host",
+    "user-agent",
+    "accept",
+    "accept-encoding",
+    "cookie",
+    "referer",
+    "content-length",
+    "content-type",
+];
+
+/// The TLS library curl was built against.
+enum Backend {
+    OpenSsl(&'static str),
+    BoringSsl,
+}
+
+impl From<Backend> for TlsOptions {
+    fn from(backend: Backend) -> Self {
+        let builder = TlsOptions::builder()
+            .alpn_protocols([AlpnProtocol::HTTP2, AlpnProtocol::HTTP1])
+            .min_tls_version(TlsVersion::TLS_1_2)
+            .max_tls_version(TlsVersion::TLS_1_3);
+
+        match backend {
+            Backend::OpenSsl(curves) => builder
+                .curves_list(curves)
+                .sigalgs_list(OPENSSL_SIGALGS_LIST)
+                .cipher_list(OPENSSL_CIPHER_LIST)
+                .preserve_tls13_cipher_list(true)
+                .build(),
+            Backend::BoringSsl => builder
+                .curves_list(BORINGSSL_CURVES)
+                .sigalgs_list(BORINGSSL_SIGALGS_LIST)
+                .cipher_list(BORINGSSL_CIPHER_LIST)
+                .build(),
+        }
+    }
+}
+
+macro_rules! mod_generator {
+    ($mod_name:ident, $backend:expr, $ua:expr) => {
+        pub(crate) mod $mod_name {
+            use super::*;
+
+            pub fn emulation(emulation: Emulation) -> wreq::Emulation {
+                build_emulation(stringify!($mod_name), emulation, $backend, $ua)
+            }
+        }
+    };
+}
+
+fn build_emulation(
+    group: &'static str,
+    emulation: Emulation,
+    backend: Backend,
+    user_agent: &'static str,
+) -> wreq::Emulation {
+    // nghttp2 settings as configured by curl, which opens a 1000 MB
+    // connection window right away.
+    let http2_options = emulation.http2.then(|| {
+        Http2Options::builder()
+            .max_concurrent_streams(100)
+            .initial_window_size(10485760)
+            .initial_connection_window_size(1048576000)
+            .enable_push(false)
+            .headers_pseudo_order(
+                PseudoOrder::builder()
+                    .extend([
+                        PseudoId::Method,
+                        PseudoId::Path,
+                        PseudoId::Scheme,
+                        PseudoId::Authority,
+                    ])
+                    .build(),
+            )
+            .settings_order(
+                SettingsOrder::builder()
+                    .extend([
+                        SettingId::MaxConcurrentStreams,
+                        SettingId::InitialWindowSize,
+                        SettingId::EnablePush,
+                    ])
+                    .build(),
+            )
+            .build()
+    });
+
+    let headers = emulation.headers.then(|| {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(user_agent));
+        headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+        headers
+    });
+
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(HEADER_ORDER));
+
+    build_standard_emulation(group, backend.into(), http2_options, headers, orig_headers)
+}
+
+mod_generator!(curl8_5, Backend::OpenSsl(OPENSSL_CURVES_1), "curl/8.5.0");
+
+mod_generator!(curl8_14, Backend::OpenSsl(OPENSSL_CURVES_2), "curl/8.14.1");
+
+mod_generator!(curl_boringssl_8_14, Backend::BoringSsl, "curl/8.14.1");
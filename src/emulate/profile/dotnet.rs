@@ -0,0 +1,129 @@
+use super::*;
+
+const CURVES_1: &str = join!(":", "X25519", "P-256", "P-521", "P-384");
+
+const CURVES_2: &str = join!(":", "X25519MLKEM768", "X25519", "P-256", "P-521", "P-384");
+
+const SIGALGS_LIST: &str = join!(
+    ":",
+    "ecdsa_secp256r1_sha256",
+    "ecdsa_secp384r1_sha384",
+    "ecdsa_secp521r1_sha512",
+    "ed25519",
+    "rsa_pss_rsae_sha256",
+    "rsa_pss_rsae_sha384",
+    "rsa_pss_rsae_sha512",
+    "rsa_pkcs1_sha256",
+    "rsa_pkcs1_sha384",
+    "rsa_pkcs1_sha512"
+);
+
+/// The cipher suites `SslStream` allows by default on Linux, which is
+/// OpenSSL's ECDHE-only subset of the system defaults.
+const CIPHER_LIST: &str = join!(
+    ":",
+    "TLS_AES_256_GCM_SHA384",
+    "TLS_CHACHA20_POLY1305_SHA256",
+    "TLS_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256"
+);
+
+/// Header order for `SocketsHttpHandler`, including request-specific headers.
+const HEADER_ORDER: &[&str] = &[
+    "host",
+    "user-agent",
+    "accept",
+    "cookie",
+    "referer",
+    "content-type",
+    "content-length",
+];
+
+macro_rules! mod_generator {
+    ($mod_name:ident, $curves:expr) => {
+        pub(crate) mod $mod_name {
+            use super::*;
+
+            pub fn emulation(emulation: Emulation) -> wreq::Emulation {
+                build_emulation(stringify!($mod_name), emulation, $curves)
+            }
+        }
+    };
+}
+
+/// `HttpClient` sends HTTP/1.1 requests unless the request (or
+/// `DefaultRequestVersion`) asks for 2.0, and only then offers `h2` in ALPN.
+/// It adds no default headers, so the `headers` option has nothing to add.
+fn build_emulation(
+    group: &'static str,
+    emulation: Emulation,
+    curves: &'static str,
+) -> wreq::Emulation {
+    let alpn_protocols: &'static [AlpnProtocol] = if emulation.http2 {
+        &[AlpnProtocol::HTTP2, AlpnProtocol::HTTP1]
+    } else {
+        &[AlpnProtocol::HTTP1]
+    };
+    let tls_options = TlsOptions::builder()
+        .curves_list(curves)
+        .sigalgs_list(SIGALGS_LIST)
+        .cipher_list(CIPHER_LIST)
+        .preserve_tls13_cipher_list(true)
+        .alpn_protocols(alpn_protocols.iter().copied())
+        .min_tls_version(TlsVersion::TLS_1_2)
+        .max_tls_version(TlsVersion::TLS_1_3)
+        .build();
+
+    // Stream windows start at the protocol default and grow with dynamic
+    // window scaling; the connection window is raised to 64 MB up front.
+    let http2_options = emulation.http2.then(|| {
+        Http2Options::builder()
+            .enable_push(false)
+            .initial_window_size(65535)
+            .max_header_list_size(65536)
+            .initial_connection_window_size(67108864)
+            .headers_pseudo_order(
+                PseudoOrder::builder()
+                    .extend([
+                        PseudoId::Method,
+                        PseudoId::Scheme,
+                        PseudoId::Authority,
+                        PseudoId::Path,
+                    ])
+                    .build(),
+            )
+            .settings_order(
+                SettingsOrder::builder()
+                    .extend([
+                        SettingId::EnablePush,
+                        SettingId::InitialWindowSize,
+                        SettingId::MaxHeaderListSize,
+                    ])
+                    .build(),
+            )
+            .build()
+    });
+
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(HEADER_ORDER));
+
+    build_standard_emulation(group, tls_options, http2_options, None, orig_headers)
+}
+
+mod_generator!(dotnet8, CURVES_1);
+
+mod_generator!(dotnet9, CURVES_1);
+
+// .NET 10 targets distributions shipping OpenSSL 3.5, which offers the hybrid
+// post-quantum group first.
+mod_generator!(dotnet10, CURVES_2);
@@ -2,6 +2,7 @@
 mod http2;
 #[macro_use]
 mod tls;
+#[macro_use]
 mod header;
 
 use header::*;
@@ -9,6 +10,40 @@ use tls::*;
 
 use super::*;
 
+/// Applies private browsing and Firefox for Android on top of any version.
+///
+/// Private windows keep no session state, so they neither offer session
+/// tickets nor resume with a PSK. Firefox for Android skips SCTs and the hybrid
+/// post-quantum group and uses a smaller HPACK table and stream window.
+/// Versions generated with `desktop_only = true` have no Android build and
+/// ignore `Platform::Android`.
+fn browsing_mode(
+    emulation: &Emulation,
+    desktop_only: bool,
+    tls_options: &mut TlsOptions,
+    http2_options: Option<&mut Http2Options>,
+) {
+    if emulation.private {
+        tls_options.session_ticket = false;
+        tls_options.pre_shared_key = false;
+        tls_options.psk_skip_session_ticket = false;
+        tls_options.psk_dhe_ke = false;
+    }
+
+    if emulation.platform == Platform::Android && !desktop_only {
+        tls_options.enable_signed_cert_timestamps = false;
+        if tls_options.curves_list.as_deref() == Some(CURVES_2) {
+            tls_options.curves_list = Some(CURVES_1.into());
+            tls_options.key_shares = Some(KEY_SHARES_1.into());
+        }
+
+        if let Some(http2_options) = http2_options {
+            http2_options.header_table_size = Some(4096);
+            http2_options.initial_window_size = 32768;
+        }
+    }
+}
+
 mod_generator!(
     ff109,
     tls_options!(2, CIPHER_LIST_1, CURVES_1),
@@ -141,6 +176,10 @@ mod_generator!(
         (
             Linux,
             "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:135.0) Gecko/20100101 Firefox/135.0"
+        ),
+        (
+            Android,
+            "Mozilla/5.0 (Android 13; Mobile; rv:135.0) Gecko/135.0 Firefox/135.0"
         )
     ]
 );
@@ -162,6 +201,10 @@ mod_generator!(
         (
             Linux,
             "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:135.0) Gecko/20100101 Firefox/135.0"
+        ),
+        (
+            Android,
+            "Mozilla/5.0 (Android 13; Mobile; rv:135.0) Gecko/135.0 Firefox/135.0"
         )
     ]
 );
@@ -193,6 +236,10 @@ mod_generator!(
         (
             Linux,
             "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:136.0) Gecko/20100101 Firefox/136.0"
+        ),
+        (
+            Android,
+            "Mozilla/5.0 (Android 13; Mobile; rv:136.0) Gecko/136.0 Firefox/136.0"
         )
     ]
 );
@@ -213,6 +260,10 @@ mod_generator!(
         (
             Linux,
             "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:136.0) Gecko/20100101 Firefox/136.0"
+        ),
+        (
+            Android,
+            "Mozilla/5.0 (Android 13; Mobile; rv:136.0) Gecko/136.0 Firefox/136.0"
         )
     ]
 );
@@ -233,6 +284,10 @@ mod_generator!(
         (
             Linux,
             "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:136.0) Gecko/20100101 Firefox/139.0"
+        ),
+        (
+            Android,
+            "Mozilla/5.0 (Android 13; Mobile; rv:139.0) Gecko/139.0 Firefox/139.0"
         )
     ]
 );
@@ -516,3 +571,96 @@ mod_generator!(
         )
     ]
 );
+
+mod_generator!(
+    ff_esr115,
+    ff109::build_emulation,
+    header_initializer,
+    [
+        (
+            Windows,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:115.0) Gecko/20100101 Firefox/115.0"
+        ),
+        (
+            MacOS,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:115.0) Gecko/20100101 Firefox/115.0"
+        ),
+        (
+            Linux,
+            "Mozilla/5.0 (X11; Linux x86_64; rv:115.0) Gecko/20100101 Firefox/115.0"
+        )
+    ],
+    desktop_only = true
+);
+
+mod_generator!(
+    ff_esr128,
+    ff128::build_emulation,
+    header_initializer_with_zstd,
+    [
+        (
+            Windows,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:128.0) Gecko/20100101 Firefox/128.0"
+        ),
+        (
+            MacOS,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:128.0) Gecko/20100101 Firefox/128.0"
+        ),
+        (
+            Linux,
+            "Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0"
+        )
+    ],
+    desktop_only = true
+);
+
+mod_generator!(
+    ff_esr140,
+    ff135::build_emulation,
+    header_initializer_with_zstd,
+    [
+        (
+            Windows,
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:140.0) Gecko/20100101 Firefox/140.0"
+        ),
+        (
+            MacOS,
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:140.0) Gecko/20100101 Firefox/140.0"
+        ),
+        (
+            Linux,
+            "Mozilla/5.0 (X11; Linux x86_64; rv:140.0) Gecko/20100101 Firefox/140.0"
+        )
+    ],
+    desktop_only = true
+);
+
+// Tor Browser is built on the matching ESR and always runs in private browsing
+// mode, and `privacy.resistFingerprinting` reports the same Windows user agent
+// on every platform.
+mod_generator!(
+    tor13_5,
+    tls_options!(7, CIPHER_LIST_1, CURVES_3, KEY_SHARES_1),
+    http2_options!(2),
+    header_initializer,
+    "Mozilla/5.0 (Windows NT 10.0; rv:115.0) Gecko/20100101 Firefox/115.0",
+    desktop_only = true
+);
+
+mod_generator!(
+    tor14_5,
+    tls_options!(8, CIPHER_LIST_2, CURVES_3, KEY_SHARES_1),
+    http2_options!(3),
+    header_initializer_with_zstd,
+    "Mozilla/5.0 (Windows NT 10.0; rv:128.0) Gecko/20100101 Firefox/128.0",
+    desktop_only = true
+);
+
+mod_generator!(
+    tor15,
+    tls_options!(8, CIPHER_LIST_1, CURVES_3, KEY_SHARES_1),
+    http2_options!(1),
+    header_initializer_with_zstd,
+    "Mozilla/5.0 (Windows NT 10.0; rv:140.0) Gecko/20100101 Firefox/140.0",
+    desktop_only = true
+);
@@ -1,14 +1,46 @@
 use super::*;
 
-pub fn header_initializer(ua: &'static str) -> HeaderMap {
+macro_rules! header_order {
+    ($header_initializer:ident) => {
+        |_: RequestKind| HEADER_ORDER
+    };
+}
+
+/// Header order for Firefox, including request-specific headers.
+pub const HEADER_ORDER: &[&str] = &[
+    "host",
+    "user-agent",
+    "accept",
+    "accept-language",
+    "accept-encoding",
+    "content-type",
+    "content-length",
+    "origin",
+    "connection",
+    "referer",
+    "cookie",
+    "upgrade-insecure-requests",
+    "sec-fetch-dest",
+    "sec-fetch-mode",
+    "sec-fetch-site",
+    "sec-fetch-user",
+    "priority",
+    "pragma",
+    "cache-control",
+    "te",
+];
+
+pub fn header_initializer(ua: &'static str, request_kind: RequestKind) -> HeaderMap {
     let mut headers = HeaderMap::new();
     header_firefox_ua!(headers, ua);
-    header_firefox_accept!(headers);
-    headers.insert(
-        HeaderName::from_static("upgrade-insecure-requests"),
-        HeaderValue::from_static("1"),
-    );
-    header_firefox_sec_fetch!(headers);
+    header_firefox_accept!(headers, request_kind);
+    if request_kind.is_navigation() {
+        headers.insert(
+            HeaderName::from_static("upgrade-insecure-requests"),
+            HeaderValue::from_static("1"),
+        );
+    }
+    header_firefox_sec_fetch!(headers, request_kind);
     headers.insert(
         HeaderName::from_static("te"),
         HeaderValue::from_static("trailers"),
@@ -16,18 +48,25 @@ pub fn header_initializer(ua: &'static str) -> HeaderMap {
     headers
 }
 
-pub fn header_initializer_with_zstd(ua: &'static str) -> HeaderMap {
+pub fn header_initializer_with_zstd(ua: &'static str, request_kind: RequestKind) -> HeaderMap {
     let mut headers = HeaderMap::new();
     header_firefox_ua!(headers, ua);
-    header_firefox_accept!(zstd, headers);
-    headers.insert(
-        HeaderName::from_static("upgrade-insecure-requests"),
-        HeaderValue::from_static("1"),
-    );
-    header_firefox_sec_fetch!(headers);
+    header_firefox_accept!(zstd, headers, request_kind);
+    if request_kind.is_navigation() {
+        headers.insert(
+            HeaderName::from_static("upgrade-insecure-requests"),
+            HeaderValue::from_static("1"),
+        );
+    }
+    header_firefox_sec_fetch!(headers, request_kind);
+    let priority = if request_kind.is_navigation() {
+        "u=0, i"
+    } else {
+        "u=4"
+    };
     headers.insert(
         HeaderName::from_static("priority"),
-        HeaderValue::from_static("u=0, i"),
+        HeaderValue::from_static(priority),
     );
     headers.insert(
         HeaderName::from_static("te"),
@@ -56,6 +56,19 @@ macro_rules! tls_options {
             .key_shares($key_shares)
             .certificate_compressors(CERTIFICATE_COMPRESSORS))
     };
+    (7, $cipher_list:expr, $curves:expr, $key_shares:expr) => {
+        tls_options!(@build tls_options!(@base FirefoxTlsConfig::builder(), $cipher_list, $curves)
+            .session_ticket(false)
+            .psk_dhe_ke(false)
+            .key_shares($key_shares))
+    };
+    (8, $cipher_list:expr, $curves:expr, $key_shares:expr) => {
+        tls_options!(@build tls_options!(@base FirefoxTlsConfig::builder(), $cipher_list, $curves)
+            .session_ticket(false)
+            .enable_ech_grease(true)
+            .psk_dhe_ke(false)
+            .key_shares($key_shares))
+    };
 }
 
 pub const CURVES_1: &str = join!(
@@ -79,6 +92,10 @@ pub const CURVES_2: &str = join!(
     "ffdhe3072"
 );
 
+/// Tor Browser drops the finite-field groups and, being built with
+/// `security.tls.enable_kyber` off, the hybrid post-quantum group.
+pub const CURVES_3: &str = join!(":", "X25519", "P-256", "P-384", "P-521");
+
 pub const KEY_SHARES_1: &[KeyShare] = &[KeyShare::X25519, KeyShare::P256];
 
 pub const KEY_SHARES_2: &[KeyShare] =
@@ -0,0 +1,159 @@
+use super::*;
+
+const CURVES_1: &str = join!(
+    ":",
+    "X25519Kyber768Draft00",
+    "X25519",
+    "P-256",
+    "P-384",
+    "P-521"
+);
+
+const CURVES_2: &str = join!(":", "X25519MLKEM768", "X25519", "P-256", "P-384", "P-521");
+
+const KEY_SHARES_1: &[KeyShare] = &[KeyShare::X25519_KYBER768_DRAFT00, KeyShare::X25519];
+
+const KEY_SHARES_2: &[KeyShare] = &[KeyShare::X25519_MLKEM768, KeyShare::X25519];
+
+const SIGALGS_LIST: &str = join!(
+    ":",
+    "rsa_pss_rsae_sha256",
+    "ecdsa_secp256r1_sha256",
+    "ed25519",
+    "rsa_pss_rsae_sha384",
+    "rsa_pss_rsae_sha512",
+    "rsa_pkcs1_sha256",
+    "rsa_pkcs1_sha384",
+    "rsa_pkcs1_sha512",
+    "ecdsa_secp384r1_sha384",
+    "ecdsa_secp521r1_sha512",
+    "rsa_pkcs1_sha1",
+    "ecdsa_sha1"
+);
+
+/// `crypto/tls` defaults on CPUs with AES hardware support. RSA key exchange
+/// suites are disabled by default since Go 1.22.
+const CIPHER_LIST: &str = join!(
+    ":",
+    "TLS_AES_128_GCM_SHA256",
+    "TLS_AES_256_GCM_SHA384",
+    "TLS_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA"
+);
+
+/// Header order for `net/http`, including request-specific headers.
+const HEADER_ORDER: &[&str] = &[
+    "host",
+    "user-agent",
+    "content-length",
+    "content-type",
+    "cookie",
+    "referer",
+    "accept-encoding",
+];
+
+macro_rules! mod_generator {
+    ($mod_name:ident, $curves:expr, $key_shares:expr, $max_frame_size:expr) => {
+        pub(crate) mod $mod_name {
+            use super::*;
+
+            pub fn emulation(emulation: Emulation) -> wreq::Emulation {
+                build_emulation(
+                    stringify!($mod_name),
+                    emulation,
+                    $curves,
+                    $key_shares,
+                    $max_frame_size,
+                )
+            }
+        }
+    };
+}
+
+fn build_emulation(
+    group: &'static str,
+    emulation: Emulation,
+    curves: &'static str,
+    key_shares: &'static [KeyShare],
+    max_frame_size: Option<u32>,
+) -> wreq::Emulation {
+    let tls_options = TlsOptions::builder()
+        .enable_ocsp_stapling(true)
+        .enable_signed_cert_timestamps(true)
+        .curves_list(curves)
+        .key_shares(key_shares)
+        .sigalgs_list(SIGALGS_LIST)
+        .cipher_list(CIPHER_LIST)
+        .preserve_tls13_cipher_list(true)
+        .alpn_protocols([AlpnProtocol::HTTP2, AlpnProtocol::HTTP1])
+        .min_tls_version(TlsVersion::TLS_1_2)
+        .max_tls_version(TlsVersion::TLS_1_3)
+        .build();
+
+    // The bundled `x/net/http2` transport opens a 1 GB connection window.
+    let http2_options = emulation.http2.then(|| {
+        Http2Options::builder()
+            .enable_push(false)
+            .initial_window_size(4194304)
+            .initial_connection_window_size(1073807359)
+            .max_frame_size(max_frame_size)
+            .max_header_list_size(10485760)
+            .headers_pseudo_order(
+                PseudoOrder::builder()
+                    .extend([
+                        PseudoId::Authority,
+                        PseudoId::Method,
+                        PseudoId::Path,
+                        PseudoId::Scheme,
+                    ])
+                    .build(),
+            )
+            .settings_order(
+                SettingsOrder::builder()
+                    .extend([
+                        SettingId::EnablePush,
+                        SettingId::InitialWindowSize,
+                        SettingId::MaxFrameSize,
+                        SettingId::MaxHeaderListSize,
+                    ])
+                    .build(),
+            )
+            .build()
+    });
+
+    // `net/http` names the protocol it speaks in its default user agent. Default
+    // headers are fixed per client, so with HTTP/2 enabled this assumes h2 over
+    // TLS, and HTTP/1 connections still send `/2.0`.
+    let headers = emulation.headers.then(|| {
+        let user_agent = if emulation.http2 {
+            "Go-http-client/2.0"
+        } else {
+            "Go-http-client/1.1"
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(user_agent));
+        #[cfg(feature = "emulation-compression")]
+        headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+        headers
+    });
+
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(HEADER_ORDER));
+
+    build_standard_emulation(group, tls_options, http2_options, headers, orig_headers)
+}
+
+mod_generator!(go1_23, CURVES_1, KEY_SHARES_1, None);
+
+// From Go 1.24 the bundled transport always advertises its 1 MB read frame size.
+mod_generator!(go1_24, CURVES_2, KEY_SHARES_2, Some(1048576));
@@ -0,0 +1,154 @@
+use super::*;
+
+const CURVES: &str = join!(
+    ":",
+    "X25519",
+    "P-256",
+    "P-384",
+    "P-521",
+    "ffdhe2048",
+    "ffdhe3072"
+);
+
+const KEY_SHARES: &[KeyShare] = &[KeyShare::X25519, KeyShare::P256];
+
+const SIGALGS_LIST: &str = join!(
+    ":",
+    "ecdsa_secp256r1_sha256",
+    "ecdsa_secp384r1_sha384",
+    "ecdsa_secp521r1_sha512",
+    "ed25519",
+    "rsa_pss_rsae_sha256",
+    "rsa_pss_rsae_sha384",
+    "rsa_pss_rsae_sha512",
+    "rsa_pkcs1_sha256",
+    "rsa_pkcs1_sha384",
+    "rsa_pkcs1_sha512",
+    "ecdsa_sha1",
+    "rsa_pkcs1_sha1"
+);
+
+/// JSSE's default cipher suites, without the DHE suites BoringSSL doesn't
+/// implement.
+const CIPHER_LIST: &str = join!(
+    ":",
+    "TLS_AES_256_GCM_SHA384",
+    "TLS_AES_128_GCM_SHA256",
+    "TLS_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA",
+    "TLS_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_RSA_WITH_AES_256_CBC_SHA256",
+    "TLS_RSA_WITH_AES_128_CBC_SHA256",
+    "TLS_RSA_WITH_AES_256_CBC_SHA",
+    "TLS_RSA_WITH_AES_128_CBC_SHA"
+);
+
+/// Header order for `java.net.http.HttpClient`, including request-specific
+/// headers.
+const HEADER_ORDER: &[&str] = &[
+    "host",
+    "cookie",
+    "referer",
+    "content-type",
+    "content-length",
+    "user-agent",
+];
+
+macro_rules! mod_generator {
+    ($mod_name:ident, $session_ticket:expr, $ua:expr) => {
+        pub(crate) mod $mod_name {
+            use super::*;
+
+            pub fn emulation(emulation: Emulation) -> wreq::Emulation {
+                build_emulation(stringify!($mod_name), emulation, $session_ticket, $ua)
+            }
+        }
+    };
+}
+
+fn build_emulation(
+    group: &'static str,
+    emulation: Emulation,
+    session_ticket: bool,
+    user_agent: &'static str,
+) -> wreq::Emulation {
+    let tls_options = TlsOptions::builder()
+        .enable_ocsp_stapling(true)
+        .session_ticket(session_ticket)
+        .curves_list(CURVES)
+        .key_shares(KEY_SHARES)
+        .sigalgs_list(SIGALGS_LIST)
+        .cipher_list(CIPHER_LIST)
+        .preserve_tls13_cipher_list(true)
+        .alpn_protocols([AlpnProtocol::HTTP2, AlpnProtocol::HTTP1])
+        .min_tls_version(TlsVersion::TLS_1_2)
+        .max_tls_version(TlsVersion::TLS_1_3)
+        .build();
+
+    // `jdk.httpclient.*` defaults: a 16 MB stream window, a 32 MB connection
+    // window and server push left enabled.
+    let http2_options = emulation.http2.then(|| {
+        Http2Options::builder()
+            .header_table_size(16384)
+            .enable_push(true)
+            .max_concurrent_streams(100)
+            .initial_window_size(16777216)
+            .max_frame_size(16384)
+            .initial_connection_window_size(33554432)
+            .headers_pseudo_order(
+                PseudoOrder::builder()
+                    .extend([
+                        PseudoId::Method,
+                        PseudoId::Scheme,
+                        PseudoId::Authority,
+                        PseudoId::Path,
+                    ])
+                    .build(),
+            )
+            .settings_order(
+                SettingsOrder::builder()
+                    .extend([
+                        SettingId::HeaderTableSize,
+                        SettingId::EnablePush,
+                        SettingId::MaxConcurrentStreams,
+                        SettingId::InitialWindowSize,
+                        SettingId::MaxFrameSize,
+                    ])
+                    .build(),
+            )
+            .build()
+    });
+
+    let headers = emulation.headers.then(|| {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(user_agent));
+        headers
+    });
+
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(HEADER_ORDER));
+
+    build_standard_emulation(group, tls_options, http2_options, headers, orig_headers)
+}
+
+// Stateless session tickets are only offered by default since JDK 13.
+mod_generator!(java11, false, "Java-http-client/11.0.25");
+
+mod_generator!(java17, true, "Java-http-client/17.0.13");
+
+mod_generator!(java21, true, "Java-http-client/21.0.5");
@@ -0,0 +1,114 @@
+use super::*;
+
+const CURVES: &str = join!(":", "X25519", "P-256", "P-521", "P-384");
+
+const SIGALGS_LIST: &str = join!(
+    ":",
+    "ecdsa_secp256r1_sha256",
+    "ecdsa_secp384r1_sha384",
+    "ecdsa_secp521r1_sha512",
+    "ed25519",
+    "rsa_pss_rsae_sha256",
+    "rsa_pss_rsae_sha384",
+    "rsa_pss_rsae_sha512",
+    "rsa_pkcs1_sha256",
+    "rsa_pkcs1_sha384",
+    "rsa_pkcs1_sha512"
+);
+
+/// Node's `tls.DEFAULT_CIPHERS` as expanded by OpenSSL, without the DHE suites
+/// BoringSSL doesn't implement.
+const CIPHER_LIST: &str = join!(
+    ":",
+    "TLS_AES_256_GCM_SHA384",
+    "TLS_CHACHA20_POLY1305_SHA256",
+    "TLS_AES_128_GCM_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA",
+    "TLS_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_RSA_WITH_AES_256_CBC_SHA256",
+    "TLS_RSA_WITH_AES_128_CBC_SHA256",
+    "TLS_RSA_WITH_AES_256_CBC_SHA",
+    "TLS_RSA_WITH_AES_128_CBC_SHA"
+);
+
+/// Header order for `fetch()`, including request-specific headers.
+const HEADER_ORDER: &[&str] = &[
+    "host",
+    "connection",
+    "content-type",
+    "cookie",
+    "referer",
+    "accept",
+    "accept-language",
+    "sec-fetch-mode",
+    "user-agent",
+    "accept-encoding",
+    "content-length",
+];
+
+macro_rules! mod_generator {
+    ($mod_name:ident, $accept_encoding:expr) => {
+        pub(crate) mod $mod_name {
+            use super::*;
+
+            pub fn emulation(emulation: Emulation) -> wreq::Emulation {
+                build_emulation(stringify!($mod_name), emulation, $accept_encoding)
+            }
+        }
+    };
+}
+
+/// `fetch()` is backed by `undici`, which only offers HTTP/1.1 unless
+/// `allowH2` is set on a custom dispatcher.
+#[cfg_attr(not(feature = "emulation-compression"), allow(unused_variables))]
+fn build_emulation(
+    group: &'static str,
+    emulation: Emulation,
+    accept_encoding: &'static str,
+) -> wreq::Emulation {
+    let tls_options = TlsOptions::builder()
+        .curves_list(CURVES)
+        .sigalgs_list(SIGALGS_LIST)
+        .cipher_list(CIPHER_LIST)
+        .preserve_tls13_cipher_list(true)
+        .alpn_protocols([AlpnProtocol::HTTP1])
+        .min_tls_version(TlsVersion::TLS_1_2)
+        .max_tls_version(TlsVersion::TLS_1_3)
+        .build();
+
+    let headers = emulation.headers.then(|| {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONNECTION, HeaderValue::from_static("keep-alive"));
+        headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+        headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("*"));
+        headers.insert("sec-fetch-mode", HeaderValue::from_static("cors"));
+        headers.insert(USER_AGENT, HeaderValue::from_static("node"));
+        #[cfg(feature = "emulation-compression")]
+        headers.insert(ACCEPT_ENCODING, HeaderValue::from_static(accept_encoding));
+        headers
+    });
+
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(HEADER_ORDER));
+
+    build_standard_emulation(group, tls_options, None, headers, orig_headers)
+}
+
+mod_generator!(node22, "gzip, deflate");
+
+mod_generator!(node24, "gzip, deflate, br, zstd");
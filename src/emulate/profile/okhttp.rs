@@ -35,6 +35,19 @@ const CIPHER_LIST: &str = join!(
     "TLS_RSA_WITH_3DES_EDE_CBC_SHA"
 );
 
+/// Header order for OkHttp, including headers added by its bridge interceptor.
+const HEADER_ORDER: &[&str] = &[
+    "accept",
+    "accept-language",
+    "content-type",
+    "content-length",
+    "host",
+    "connection",
+    "user-agent",
+    "accept-encoding",
+    "cookie",
+];
+
 #[derive(TypedBuilder)]
 struct OkHttpTlsConfig {
     #[builder(default = CURVES)]
@@ -118,13 +131,30 @@ fn build_emulation(
         let mut headers = HeaderMap::new();
         headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
         headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.9"));
-        headers.insert(USER_AGENT, HeaderValue::from_static(user_agent));
+        match &emulation.app {
+            // Keep the OkHttp token from the captured user agent, so it always
+            // matches the fingerprint.
+            Some(app) => {
+                let token = user_agent.rsplit(' ').next().unwrap_or(user_agent);
+                headers.insert(USER_AGENT, app.user_agent(token));
+            }
+            None => {
+                headers.insert(USER_AGENT, HeaderValue::from_static(user_agent));
+            }
+        }
         #[cfg(feature = "emulation-compression")]
         headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+        if let Some(app) = &emulation.app {
+            app.extend_headers(&mut headers);
+        }
         headers
     });
 
-    build_standard_emulation(group, tls_options, http2_options, headers)
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(HEADER_ORDER));
+
+    build_standard_emulation(group, tls_options, http2_options, headers, orig_headers)
 }
 
 mod_generator!(
@@ -2,6 +2,7 @@
 mod http2;
 #[macro_use]
 mod tls;
+#[macro_use]
 mod header;
 
 use header::*;
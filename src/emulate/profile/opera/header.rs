@@ -1,10 +1,18 @@
 use super::*;
 
+// Opera sends headers in Chromium's order.
+macro_rules! header_order {
+    ($header_initializer:ident) => {
+        chrome::header::header_order_for
+    };
+}
+
 #[inline]
 pub fn header_initializer_with_zstd_priority(
     sec_ch_ua: &'static str,
     ua: &'static str,
     emulation_os: Platform,
+    request_kind: RequestKind,
 ) -> HeaderMap {
     let mut headers = HeaderMap::new();
     header_chrome_sec_ch_ua!(
@@ -13,17 +21,11 @@ pub fn header_initializer_with_zstd_priority(
         emulation_os.platform(),
         emulation_os.is_mobile()
     );
-    headers.insert(
-        HeaderName::from_static("upgrade-insecure-requests"),
-        HeaderValue::from_static("1"),
-    );
+    header_chrome_upgrade_insecure_requests!(headers, request_kind);
     header_chrome_ua!(headers, ua);
-    header_chrome_accept!(headers);
-    header_chrome_sec_fetch!(headers);
+    header_chrome_accept!(headers, request_kind);
+    header_chrome_sec_fetch!(headers, request_kind);
     header_chrome_accept_encoding!(zstd, headers);
-    headers.insert(
-        HeaderName::from_static("priority"),
-        HeaderValue::from_static("u=0, i"),
-    );
+    header_chrome_priority!(headers, request_kind);
     headers
 }
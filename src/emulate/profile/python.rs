@@ -0,0 +1,172 @@
+use super::*;
+
+const CURVES: &str = join!(":", "X25519", "P-256", "P-521", "P-384");
+
+const SIGALGS_LIST: &str = join!(
+    ":",
+    "ecdsa_secp256r1_sha256",
+    "ecdsa_secp384r1_sha384",
+    "ecdsa_secp521r1_sha512",
+    "ed25519",
+    "rsa_pss_rsae_sha256",
+    "rsa_pss_rsae_sha384",
+    "rsa_pss_rsae_sha512",
+    "rsa_pkcs1_sha256",
+    "rsa_pkcs1_sha384",
+    "rsa_pkcs1_sha512"
+);
+
+/// The `ssl` module's default cipher string as expanded by OpenSSL, without
+/// the DHE suites BoringSSL doesn't implement.
+const CIPHER_LIST: &str = join!(
+    ":",
+    "TLS_AES_256_GCM_SHA384",
+    "TLS_CHACHA20_POLY1305_SHA256",
+    "TLS_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+    "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384",
+    "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256",
+    "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256"
+);
+
+/// Header order for `requests`, including request-specific headers.
+const REQUESTS_HEADER_ORDER: &[&str] = &[
+    "host",
+    "user-agent",
+    "accept-encoding",
+    "accept",
+    "connection",
+    "cookie",
+    "referer",
+    "content-length",
+    "content-type",
+];
+
+/// Header order for `httpx`, including request-specific headers.
+const HTTPX_HEADER_ORDER: &[&str] = &[
+    "host",
+    "accept",
+    "accept-encoding",
+    "connection",
+    "user-agent",
+    "cookie",
+    "referer",
+    "content-length",
+    "content-type",
+];
+
+/// The Python HTTP client library.
+enum Library {
+    /// `requests` on top of `urllib3`, which only speaks HTTP/1.1.
+    Requests,
+    /// `httpx`, which speaks HTTP/2 when installed with the `http2` extra.
+    Httpx,
+}
+
+macro_rules! mod_generator {
+    ($mod_name:ident, $library:expr, $ua:expr) => {
+        pub(crate) mod $mod_name {
+            use super::*;
+
+            pub fn emulation(emulation: Emulation) -> wreq::Emulation {
+                build_emulation(stringify!($mod_name), emulation, $library, $ua)
+            }
+        }
+    };
+}
+
+fn build_emulation(
+    group: &'static str,
+    emulation: Emulation,
+    library: Library,
+    user_agent: &'static str,
+) -> wreq::Emulation {
+    let http2 = emulation.http2 && matches!(library, Library::Httpx);
+
+    let alpn_protocols: &'static [AlpnProtocol] = if http2 {
+        &[AlpnProtocol::HTTP2, AlpnProtocol::HTTP1]
+    } else {
+        &[AlpnProtocol::HTTP1]
+    };
+    let tls_options = TlsOptions::builder()
+        .curves_list(CURVES)
+        .sigalgs_list(SIGALGS_LIST)
+        .cipher_list(CIPHER_LIST)
+        .preserve_tls13_cipher_list(true)
+        .alpn_protocols(alpn_protocols.iter().copied())
+        .min_tls_version(TlsVersion::TLS_1_2)
+        .max_tls_version(TlsVersion::TLS_1_3)
+        .build();
+
+    // `h2`'s client defaults as adjusted by `httpcore`, which also widens the
+    // connection window by 16 MB.
+    let http2_options = http2.then(|| {
+        Http2Options::builder()
+            .header_table_size(4096)
+            .enable_push(false)
+            .initial_window_size(65535)
+            .max_frame_size(16384)
+            .max_concurrent_streams(100)
+            .max_header_list_size(65536)
+            .initial_connection_window_size(16842751)
+            .headers_pseudo_order(
+                PseudoOrder::builder()
+                    .extend([
+                        PseudoId::Method,
+                        PseudoId::Authority,
+                        PseudoId::Scheme,
+                        PseudoId::Path,
+                    ])
+                    .build(),
+            )
+            .settings_order(
+                SettingsOrder::builder()
+                    .extend([
+                        SettingId::HeaderTableSize,
+                        SettingId::EnablePush,
+                        SettingId::InitialWindowSize,
+                        SettingId::MaxFrameSize,
+                        SettingId::MaxConcurrentStreams,
+                        SettingId::MaxHeaderListSize,
+                    ])
+                    .build(),
+            )
+            .build()
+    });
+
+    let headers = emulation.headers.then(|| {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(user_agent));
+        #[cfg(feature = "emulation-compression")]
+        headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip, deflate"));
+        headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+        if !http2 {
+            headers.insert(CONNECTION, HeaderValue::from_static("keep-alive"));
+        }
+        headers
+    });
+
+    let header_order = match library {
+        Library::Requests => REQUESTS_HEADER_ORDER,
+        Library::Httpx => HTTPX_HEADER_ORDER,
+    };
+    let orig_headers = emulation
+        .header_order
+        .then(|| build_orig_headers(header_order));
+
+    build_standard_emulation(group, tls_options, http2_options, headers, orig_headers)
+}
+
+mod_generator!(
+    python_requests_2_32,
+    Library::Requests,
+    "python-requests/2.32.3"
+);
+
+mod_generator!(httpx0_28, Library::Httpx, "python-httpx/0.28.1");
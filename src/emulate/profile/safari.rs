@@ -1,7 +1,8 @@
 #[macro_use]
 mod http2;
 #[macro_use]
-mod tls;
+pub(super) mod tls;
+#[macro_use]
 mod header;
 
 use header::*;
@@ -220,3 +221,24 @@ mod_generator!(
     header_initializer_for_18,
     "Mozilla/5.0 (iPad; CPU OS 18_7 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/26.2 Mobile/15E148 Safari/604.1"
 );
+
+mod_generator!(
+    webview_ios_26,
+    safari26::build_emulation,
+    header_initializer_for_webview,
+    "Mozilla/5.0 (iPhone; CPU iPhone OS 18_7 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148"
+);
+
+mod_generator!(
+    instagram_ios_26,
+    safari26::build_emulation,
+    header_initializer_for_webview,
+    "Mozilla/5.0 (iPhone; CPU iPhone OS 18_7 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 Instagram 404.0.0.36.82 (iPhone16,2; iOS 26_1; en_US; en; scale=3.00; 1290x2796; 812415067; IABMV/1)"
+);
+
+mod_generator!(
+    facebook_ios_26,
+    safari26::build_emulation,
+    header_initializer_for_webview,
+    "Mozilla/5.0 (iPhone; CPU iPhone OS 18_7 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 [FBAN/FBIOS;FBAV/538.0.0.46.91;FBBV/812960431;FBDV/iPhone16,2;FBMD/iPhone;FBSN/iOS;FBSV/26.1;FBSS/3;FBID/phone;FBLC/en_US;FBOP/5;FBRV/0;IABMV/1]"
+);
@@ -1,13 +1,86 @@
 use super::*;
 
+macro_rules! header_order {
+    (header_initializer_for_15) => {
+        |_: RequestKind| HEADER_ORDER_15
+    };
+    (header_initializer_for_16_17) => {
+        |_: RequestKind| HEADER_ORDER_16_17
+    };
+    (header_initializer_for_18) => {
+        |_: RequestKind| HEADER_ORDER_18
+    };
+    (header_initializer_for_webview) => {
+        |_: RequestKind| HEADER_ORDER_18
+    };
+}
+
+/// Header order for Safari 15, including request-specific headers.
+pub const HEADER_ORDER_15: &[&str] = &[
+    "host",
+    "content-type",
+    "origin",
+    "cookie",
+    "content-length",
+    "user-agent",
+    "accept",
+    "referer",
+    "accept-language",
+    "accept-encoding",
+    "connection",
+];
+
+/// Header order for Safari 16 and 17, including request-specific headers.
+pub const HEADER_ORDER_16_17: &[&str] = &[
+    "host",
+    "content-type",
+    "accept",
+    "sec-fetch-site",
+    "origin",
+    "cookie",
+    "accept-encoding",
+    "sec-fetch-mode",
+    "user-agent",
+    "referer",
+    "content-length",
+    "accept-language",
+    "sec-fetch-dest",
+    "connection",
+];
+
+/// Header order for Safari 18 and later, including request-specific headers.
+pub const HEADER_ORDER_18: &[&str] = &[
+    "host",
+    "content-type",
+    "origin",
+    "sec-fetch-dest",
+    "user-agent",
+    "accept",
+    "referer",
+    "sec-fetch-site",
+    "sec-fetch-mode",
+    "content-length",
+    "accept-language",
+    "priority",
+    "accept-encoding",
+    "cookie",
+    "connection",
+];
+
 #[inline]
-pub fn header_initializer_for_15(ua: &'static str) -> HeaderMap {
+fn accept(request_kind: RequestKind) -> HeaderValue {
+    if request_kind.is_navigation() {
+        HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8")
+    } else {
+        HeaderValue::from_static("*/*")
+    }
+}
+
+#[inline]
+pub fn header_initializer_for_15(ua: &'static str, request_kind: RequestKind) -> HeaderMap {
     let mut headers = HeaderMap::new();
     headers.insert(USER_AGENT, HeaderValue::from_static(ua));
-    headers.insert(
-        ACCEPT,
-        HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"),
-    );
+    headers.insert(ACCEPT, accept(request_kind));
     headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.9"));
     #[cfg(feature = "emulation-compression")]
     headers.insert(
@@ -18,38 +91,55 @@ pub fn header_initializer_for_15(ua: &'static str) -> HeaderMap {
 }
 
 #[inline]
-pub fn header_initializer_for_16_17(ua: &'static str) -> HeaderMap {
+pub fn header_initializer_for_16_17(ua: &'static str, request_kind: RequestKind) -> HeaderMap {
     let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, accept(request_kind));
     headers.insert(
-        ACCEPT,
-        HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"),
+        "sec-fetch-site",
+        HeaderValue::from_static(request_kind.sec_fetch_site()),
     );
-    headers.insert("sec-fetch-site", HeaderValue::from_static("none"));
     #[cfg(feature = "emulation-compression")]
     headers.insert(
         ACCEPT_ENCODING,
         HeaderValue::from_static("gzip, deflate, br"),
     );
-    headers.insert("sec-fetch-mode", HeaderValue::from_static("navigate"));
+    headers.insert(
+        "sec-fetch-mode",
+        HeaderValue::from_static(request_kind.sec_fetch_mode()),
+    );
     headers.insert(USER_AGENT, HeaderValue::from_static(ua));
     headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.9"));
-    headers.insert("sec-fetch-dest", HeaderValue::from_static("document"));
+    headers.insert(
+        "sec-fetch-dest",
+        HeaderValue::from_static(request_kind.sec_fetch_dest()),
+    );
     headers
 }
 
 #[inline]
-pub fn header_initializer_for_18(ua: &'static str) -> HeaderMap {
+pub fn header_initializer_for_18(ua: &'static str, request_kind: RequestKind) -> HeaderMap {
     let mut headers = HeaderMap::new();
-    headers.insert("sec-fetch-dest", HeaderValue::from_static("document"));
+    headers.insert(
+        "sec-fetch-dest",
+        HeaderValue::from_static(request_kind.sec_fetch_dest()),
+    );
     headers.insert(USER_AGENT, HeaderValue::from_static(ua));
+    headers.insert(ACCEPT, accept(request_kind));
     headers.insert(
-        ACCEPT,
-        HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"),
+        "sec-fetch-site",
+        HeaderValue::from_static(request_kind.sec_fetch_site()),
+    );
+    headers.insert(
+        "sec-fetch-mode",
+        HeaderValue::from_static(request_kind.sec_fetch_mode()),
     );
-    headers.insert("sec-fetch-site", HeaderValue::from_static("none"));
-    headers.insert("sec-fetch-mode", HeaderValue::from_static("navigate"));
     headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.9"));
-    headers.insert("priority", HeaderValue::from_static("u=0, i"));
+    let priority = if request_kind.is_navigation() {
+        "u=0, i"
+    } else {
+        "u=3, i"
+    };
+    headers.insert("priority", HeaderValue::from_static(priority));
     #[cfg(feature = "emulation-compression")]
     headers.insert(
         ACCEPT_ENCODING,
@@ -57,3 +147,11 @@ pub fn header_initializer_for_18(ua: &'static str) -> HeaderMap {
     );
     headers
 }
+
+/// In-app `WKWebView`s share Safari 18's headers, minus the `priority` header.
+#[inline]
+pub fn header_initializer_for_webview(ua: &'static str, request_kind: RequestKind) -> HeaderMap {
+    let mut headers = header_initializer_for_18(ua, request_kind);
+    headers.remove("priority");
+    headers
+}
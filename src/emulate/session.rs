@@ -0,0 +1,586 @@
+//! Browser-like page loads.
+//!
+//! A [`BrowserSession`] loads a page the way a browser does: it requests the
+//! document as a navigation and then fetches the subresources the document
+//! links to, such as stylesheets, scripts and the favicon, each with the
+//! headers and priority the emulated browser uses for that kind of resource.
+//!
+//! Some anti-bot systems flag clients that only ever fetch HTML documents.
+//! Replaying the subresource requests of a page load also warms up the
+//! connection the same way a browser would.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use wreq::Client;
+//! use wreq_util::{Emulation, emulate::session::BrowserSession};
+//!
+//! # async fn run() -> wreq::Result<()> {
+//! let session = BrowserSession::builder()
+//!     .client(Client::builder().cookie_store(true).build()?)
+//!     .emulation(Emulation::builder().profile(Emulation::Chrome147).build())
+//!     .build();
+//!
+//! let page = session.load("https://example.com/").await?;
+//! println!("{} ({} subresources)", page.status(), page.subresources().len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Notes
+//!
+//! - The session's [`Emulation`] is applied per request and replaces the client's default headers
+//! - Redirects and cookies are handled by the client, so configure them there
+//! - Subresources are discovered with a lightweight scan of the document, not a full HTML parser:
+//!   `<link rel="stylesheet">`, classic and module `<script src>`, `<link rel="icon">` and `<base
+//!   href>` are recognized
+//! - Subresource failures are recorded in the [`PageLoad`] rather than failing the load
+
+use std::{future::poll_fn, task::Poll};
+
+use http::{
+    HeaderMap, HeaderValue, StatusCode, Uri,
+    header::{ACCEPT, CONTENT_TYPE, REFERER, USER_AGENT},
+};
+use typed_builder::TypedBuilder;
+use wreq::{Client, IntoUri};
+
+use super::{Emulation, RequestKind};
+use crate::uri;
+
+const SEC_CH_UA: &str = "sec-ch-ua";
+const SEC_FETCH_SITE: &str = "sec-fetch-site";
+const SEC_FETCH_MODE: &str = "sec-fetch-mode";
+const SEC_FETCH_DEST: &str = "sec-fetch-dest";
+const PRIORITY: &str = "priority";
+
+/// Loads pages like a browser, including their subresources.
+///
+/// See the [module docs](self) for details.
+#[derive(Clone, TypedBuilder)]
+pub struct BrowserSession {
+    /// The client used to send every request of the session.
+    client: Client,
+
+    /// The emulation applied to every request of the session.
+    #[builder(default)]
+    emulation: Emulation,
+
+    /// Whether to fetch the stylesheets and scripts linked from the document.
+    #[builder(default = true)]
+    subresources: bool,
+
+    /// Whether to fetch the favicon.
+    #[builder(default = true)]
+    favicon: bool,
+
+    /// The maximum number of subresource requests in flight at once.
+    ///
+    /// Defaults to 6, the per-host connection limit browsers use for HTTP/1.1.
+    #[builder(default = 6)]
+    max_concurrency: usize,
+}
+
+/// The kind of resource a subresource request loads, as sent in `sec-fetch-dest`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Destination {
+    /// A stylesheet from `<link rel="stylesheet">`.
+    Style,
+    /// A script from `<script src>`.
+    Script,
+    /// An image, such as the favicon.
+    Image,
+}
+
+/// The result of loading a page with [`BrowserSession::load`].
+#[derive(Debug)]
+pub struct PageLoad {
+    uri: Uri,
+    status: StatusCode,
+    headers: HeaderMap,
+    text: String,
+    subresources: Vec<Subresource>,
+}
+
+/// A subresource fetched while loading a page.
+#[derive(Debug)]
+pub struct Subresource {
+    uri: Uri,
+    destination: Destination,
+    result: wreq::Result<StatusCode>,
+}
+
+/// A subresource discovered in a document.
+#[derive(Debug)]
+struct Target {
+    uri: Uri,
+    destination: Destination,
+    cors: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Family {
+    Chromium,
+    Firefox,
+    Safari,
+    Other,
+}
+
+// ===== impl BrowserSession =====
+
+impl BrowserSession {
+    /// Load a page: request the document, then its subresources.
+    ///
+    /// Subresources are only fetched for successful HTML responses. The
+    /// document body is read in full and returned in the [`PageLoad`].
+    pub async fn load<U: IntoUri>(&self, uri: U) -> wreq::Result<PageLoad> {
+        let response = self
+            .client
+            .get(uri)
+            .emulation(self.emulation_for(RequestKind::Navigate))
+            .default_headers(false)
+            .send()
+            .await?;
+
+        let uri = response.uri().clone();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let text = response.text().await?;
+
+        let is_html = headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.to_ascii_lowercase().contains("html"));
+
+        let subresources = if status.is_success() && is_html {
+            let targets = self.discover(&uri, &text);
+            self.fetch_all(&uri, targets).await
+        } else {
+            Vec::new()
+        };
+
+        Ok(PageLoad {
+            uri,
+            status,
+            headers,
+            text,
+            subresources,
+        })
+    }
+
+    fn emulation_for(&self, request_kind: RequestKind) -> Emulation {
+        Emulation {
+            request_kind,
+            ..self.emulation.clone()
+        }
+    }
+
+    fn discover(&self, document: &Uri, html: &str) -> Vec<Target> {
+        let tags = parse_tags(html);
+        let base = tags
+            .iter()
+            .filter(|tag| tag.name == "base")
+            .find_map(|tag| tag.attr("href"))
+            .and_then(|href| uri::resolve(document, href))
+            .unwrap_or_else(|| document.clone());
+
+        let mut targets = Vec::<Target>::new();
+        let mut icon = None;
+        let mut push = |target: Option<Target>| {
+            if let Some(target) = target.filter(|target| {
+                matches!(target.uri.scheme_str(), Some("http" | "https"))
+                    && targets.iter().all(|other| other.uri != target.uri)
+            }) {
+                targets.push(target);
+            }
+        };
+
+        for tag in &tags {
+            let target = |href: &str, destination, cors| {
+                uri::resolve(&base, &href.replace("&amp;", "&")).map(|uri| Target {
+                    uri,
+                    destination,
+                    cors,
+                })
+            };
+
+            match tag.name.as_str() {
+                "link" => {
+                    let rel = tag.attr("rel").unwrap_or_default().to_ascii_lowercase();
+                    let mut rel = rel.split_ascii_whitespace();
+                    let Some(href) = tag.attr("href") else {
+                        continue;
+                    };
+                    if rel.clone().any(|token| token == "stylesheet")
+                        && !rel.clone().any(|token| token == "alternate")
+                    {
+                        if self.subresources {
+                            push(target(href, Destination::Style, false));
+                        }
+                    } else if rel.any(|token| token == "icon") && icon.is_none() {
+                        icon = target(href, Destination::Image, false);
+                    }
+                }
+                "script" if self.subresources => {
+                    let kind = tag.attr("type").unwrap_or_default().to_ascii_lowercase();
+                    let module = kind == "module";
+                    let classic = kind.is_empty() || kind.contains("javascript");
+                    if let Some(src) = tag.attr("src").filter(|_| module || classic) {
+                        push(target(src, Destination::Script, module));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Browsers request the favicon once the document has been parsed.
+        if self.favicon {
+            let fallback = || {
+                uri::resolve(document, "/favicon.ico").map(|uri| Target {
+                    uri,
+                    destination: Destination::Image,
+                    cors: false,
+                })
+            };
+            push(icon.or_else(fallback));
+        }
+
+        targets
+    }
+
+    async fn fetch_all(&self, document: &Uri, targets: Vec<Target>) -> Vec<Subresource> {
+        let limit = self.max_concurrency.max(1);
+        let mut queued = targets.into_iter().enumerate();
+        let mut running = Vec::with_capacity(limit);
+        let mut done = Vec::with_capacity(queued.len());
+
+        poll_fn(|cx| {
+            loop {
+                while running.len() < limit {
+                    match queued.next() {
+                        Some((index, target)) => {
+                            running.push((index, Box::pin(self.fetch(document, target))))
+                        }
+                        None => break,
+                    }
+                }
+
+                let in_flight = running.len();
+                running.retain_mut(|(index, future)| match future.as_mut().poll(cx) {
+                    Poll::Ready(subresource) => {
+                        done.push((*index, subresource));
+                        false
+                    }
+                    Poll::Pending => true,
+                });
+
+                if running.is_empty() && queued.len() == 0 {
+                    return Poll::Ready(());
+                }
+                if running.len() == in_flight {
+                    return Poll::Pending;
+                }
+            }
+        })
+        .await;
+
+        done.sort_by_key(|(index, _)| *index);
+        done.into_iter()
+            .map(|(_, subresource)| subresource)
+            .collect()
+    }
+
+    async fn fetch(&self, document: &Uri, target: Target) -> Subresource {
+        let result = self.send(document, &target).await;
+        Subresource {
+            uri: target.uri,
+            destination: target.destination,
+            result,
+        }
+    }
+
+    async fn send(&self, document: &Uri, target: &Target) -> wreq::Result<StatusCode> {
+        // Fetch requests carry the subresource header set and order of the
+        // emulated browser; only the per-destination values differ.
+        let mut request = self
+            .client
+            .get(target.uri.clone())
+            .emulation(self.emulation_for(RequestKind::JsonFetch))
+            .default_headers(false)
+            .build()?;
+        target.apply(request.headers_mut(), document);
+
+        let response = self.client.execute(request).await?;
+        let status = response.status();
+        response.bytes().await?;
+        Ok(status)
+    }
+}
+
+// ===== impl Destination =====
+
+impl Destination {
+    /// The `sec-fetch-dest` value for this destination.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Destination::Style => "style",
+            Destination::Script => "script",
+            Destination::Image => "image",
+        }
+    }
+
+    const fn accept(&self, family: Family) -> &'static str {
+        match (self, family) {
+            (Destination::Style, Family::Other) => "*/*",
+            (Destination::Style, _) => "text/css,*/*;q=0.1",
+            (Destination::Script, _) => "*/*",
+            (Destination::Image, Family::Chromium) => {
+                "image/avif,image/webp,image/apng,image/svg+xml,image/*,*/*;q=0.8"
+            }
+            (Destination::Image, Family::Firefox) => {
+                "image/avif,image/webp,image/png,image/svg+xml,image/*;q=0.8,*/*;q=0.5"
+            }
+            (Destination::Image, Family::Safari) => {
+                "image/webp,image/avif,image/jxl,image/heic,image/heic-sequence,video/*;q=0.8,image/png,image/svg+xml,image/*;q=0.8,*/*;q=0.5"
+            }
+            (Destination::Image, Family::Other) => "*/*",
+        }
+    }
+
+    const fn priority(&self, family: Family) -> &'static str {
+        match (self, family) {
+            (Destination::Style, Family::Chromium) => "u=0",
+            (Destination::Script, Family::Chromium) => "u=1",
+            (Destination::Style | Destination::Script, Family::Firefox) => "u=2",
+            (Destination::Style, _) => "u=1",
+            (Destination::Script, _) => "u=2",
+            (Destination::Image, _) => "u=4, i",
+        }
+    }
+}
+
+// ===== impl PageLoad =====
+
+impl PageLoad {
+    /// Returns the final URI of the document.
+    #[inline]
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    /// Returns the status code of the document response.
+    #[inline]
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the headers of the document response.
+    #[inline]
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Returns the document body.
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the subresources fetched for the page, in request order.
+    #[inline]
+    pub fn subresources(&self) -> &[Subresource] {
+        &self.subresources
+    }
+}
+
+// ===== impl Subresource =====
+
+impl Subresource {
+    /// Returns the URI of the subresource.
+    #[inline]
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    /// Returns the destination the subresource was requested as.
+    #[inline]
+    pub fn destination(&self) -> Destination {
+        self.destination
+    }
+
+    /// Returns the status code, or the error if the request failed.
+    #[inline]
+    pub fn result(&self) -> Result<StatusCode, &wreq::Error> {
+        self.result.as_ref().copied()
+    }
+}
+
+// ===== impl Target =====
+
+impl Target {
+    /// Turn fetch headers from the emulation into the headers for this subresource.
+    fn apply(&self, headers: &mut HeaderMap, document: &Uri) {
+        let family = Family::detect(headers);
+
+        headers.remove(CONTENT_TYPE);
+        headers.insert(
+            ACCEPT,
+            HeaderValue::from_static(self.destination.accept(family)),
+        );
+
+        if headers.contains_key(SEC_FETCH_DEST) {
+            let site = if uri::same_origin(document, &self.uri) {
+                "same-origin"
+            } else if uri::same_site(document, &self.uri) {
+                "same-site"
+            } else {
+                "cross-site"
+            };
+            let mode = if self.cors { "cors" } else { "no-cors" };
+
+            headers.insert(SEC_FETCH_SITE, HeaderValue::from_static(site));
+            headers.insert(SEC_FETCH_MODE, HeaderValue::from_static(mode));
+            headers.insert(
+                SEC_FETCH_DEST,
+                HeaderValue::from_static(self.destination.as_str()),
+            );
+        }
+
+        if headers.contains_key(PRIORITY) {
+            headers.insert(
+                PRIORITY,
+                HeaderValue::from_static(self.destination.priority(family)),
+            );
+        }
+
+        // The default referrer policy, strict-origin-when-cross-origin.
+        let referer = if uri::same_origin(document, &self.uri) {
+            Some(document.to_string())
+        } else if document.scheme_str() == Some("https") && self.uri.scheme_str() != Some("https") {
+            None
+        } else {
+            uri::origin(document).map(|origin| origin + "/")
+        };
+        if let Some(referer) = referer.and_then(|referer| HeaderValue::try_from(referer).ok()) {
+            headers.insert(REFERER, referer);
+        }
+    }
+}
+
+// ===== impl Family =====
+
+impl Family {
+    /// Identify the browser family from the headers generated by the emulation.
+    fn detect(headers: &HeaderMap) -> Family {
+        if headers.contains_key(SEC_CH_UA) {
+            return Family::Chromium;
+        }
+
+        match headers.get(USER_AGENT).and_then(|ua| ua.to_str().ok()) {
+            Some(ua) if ua.contains("Firefox/") => Family::Firefox,
+            Some(ua) if ua.contains("Safari/") => Family::Safari,
+            _ => Family::Other,
+        }
+    }
+}
+
+/// A start tag and its attributes.
+struct Tag<'a> {
+    name: String,
+    attrs: Vec<(String, &'a str)>,
+}
+
+impl<'a> Tag<'a> {
+    fn attr(&self, name: &str) -> Option<&'a str> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Scan the start tags of an HTML document.
+///
+/// Comments and the contents of `<script>` and `<style>` elements are skipped.
+fn parse_tags(html: &str) -> Vec<Tag<'_>> {
+    let mut tags = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        if name_len == 0 {
+            continue;
+        }
+        let name = rest[..name_len].to_ascii_lowercase();
+        rest = &rest[name_len..];
+
+        let mut attrs = Vec::new();
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+            if rest.is_empty() || rest.starts_with('>') {
+                break;
+            }
+
+            let key_len = rest
+                .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/'))
+                .unwrap_or(rest.len());
+            if key_len == 0 {
+                // A stray `=`; skip it.
+                rest = &rest[1..];
+                continue;
+            }
+            let key = rest[..key_len].to_ascii_lowercase();
+            rest = rest[key_len..].trim_start();
+
+            let mut value = "";
+            if let Some(after) = rest.strip_prefix('=') {
+                let after = after.trim_start();
+                (value, rest) = match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let inner = &after[1..];
+                        match inner.find(quote) {
+                            Some(end) => (&inner[..end], &inner[end + 1..]),
+                            None => (inner, ""),
+                        }
+                    }
+                    _ => {
+                        let end = after
+                            .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                            .unwrap_or(after.len());
+                        after.split_at(end)
+                    }
+                };
+            }
+            attrs.push((key, value));
+        }
+
+        // Raw text elements may contain markup-like strings.
+        if matches!(name.as_str(), "script" | "style") {
+            rest = find_end_tag(rest, &name).map_or("", |end| &rest[end..]);
+        }
+
+        tags.push(Tag { name, attrs });
+    }
+
+    tags
+}
+
+/// Finds the start of the `</name` end tag, ignoring ASCII case.
+fn find_end_tag(html: &str, name: &str) -> Option<usize> {
+    html.match_indices("</").map(|(i, _)| i).find(|&i| {
+        html.as_bytes()[i + 2..]
+            .get(..name.len())
+            .is_some_and(|tag| tag.eq_ignore_ascii_case(name.as_bytes()))
+    })
+}
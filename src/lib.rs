@@ -5,10 +5,35 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![doc = include_str!("../README.md")]
 
+#[cfg(all(
+    feature = "tower-delay",
+    not(any(feature = "tokio-rt", feature = "compio-rt"))
+))]
+compile_error!("`tower-delay` needs a timer, enable `tokio-rt` or `compio-rt`");
+
+// The runtime crates are only used by the timing and concurrency middleware.
+#[cfg(all(
+    feature = "tokio-rt",
+    not(any(feature = "tower-delay", feature = "tower-concurrency"))
+))]
+use tokio as _;
+#[cfg(all(feature = "compio-rt", not(feature = "tower-delay")))]
+use {compio as _, send_wrapper as _};
+
 #[cfg(feature = "emulation")]
 pub mod emulate;
+#[cfg(any(feature = "emulation", feature = "tower-delay"))]
 mod rand;
 pub mod tower;
+#[cfg(any(
+    feature = "tower-redirect",
+    feature = "tower-rate",
+    feature = "tower-concurrency",
+    feature = "emulation-session"
+))]
+mod uri;
 
 #[cfg(feature = "emulation")]
-pub use self::emulate::{Emulation, Platform, Profile};
+pub use self::emulate::{App, Emulation, InvalidApp, Platform, Profile, RequestKind};
+#[cfg(any(feature = "emulation", feature = "tower-delay"))]
+pub use self::rand::Rng;
@@ -2,10 +2,68 @@ use std::{
     cell::Cell,
     collections::hash_map::RandomState,
     hash::{BuildHasher, Hasher},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
 };
 
+/// The SplitMix64 increment, 2^64 divided by the golden ratio.
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// A source of randomness for random emulation and delay jitter.
+///
+/// [`Rng::default`] draws from per-thread randomly keyed state. A seeded
+/// [`Rng`] produces the same sequence on every run, which makes jitter
+/// reproducible in tests. Clones share the sequence of the original.
+///
+/// # Example
+///
+/// ```
+/// use wreq_util::Rng;
+///
+/// let (a, b) = (Rng::seeded(7), Rng::seeded(7));
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Rng {
+    // SplitMix64 state, or `None` for the thread-local source.
+    seeded: Option<Arc<AtomicU64>>,
+}
+
+impl Rng {
+    /// Create an [`Rng`] producing a reproducible sequence from `seed`.
+    pub fn seeded(seed: u64) -> Self {
+        Rng {
+            seeded: Some(Arc::new(AtomicU64::new(seed))),
+        }
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&self) -> u64 {
+        match &self.seeded {
+            Some(state) => {
+                let mut z = state
+                    .fetch_add(GOLDEN_GAMMA, Ordering::Relaxed)
+                    .wrapping_add(GOLDEN_GAMMA);
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+                z ^ (z >> 31)
+            }
+            None => fast_random(),
+        }
+    }
+
+    /// Returns a random float uniformly distributed in `[0, 1)`.
+    #[cfg(feature = "tower-delay")]
+    pub(crate) fn next_f64(&self) -> f64 {
+        // Keep the 53 bits that fit in the mantissa of an `f64`.
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
 // from: https://github.com/seanmonstar/reqwest/blob/5d5bf355744b181d31533501133ad9fbf99e8849/src/util.rs#L28
-pub(crate) fn fast_random() -> u64 {
+fn fast_random() -> u64 {
     thread_local! {
         static KEY: RandomState = RandomState::new();
         static COUNTER: Cell<u64> = const { Cell::new(0) };
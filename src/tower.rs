@@ -5,3 +5,33 @@
 
 #[cfg(feature = "tower-delay")]
 pub mod delay;
+
+#[cfg(feature = "tower-delay")]
+pub mod timer;
+
+#[cfg(feature = "tower-cookie")]
+pub mod cookie;
+
+#[cfg(feature = "tower-redirect")]
+pub mod redirect;
+
+#[cfg(feature = "tower-rate")]
+pub mod rate;
+
+#[cfg(feature = "tower-backoff")]
+pub mod backoff;
+
+#[cfg(feature = "tower-retry")]
+pub mod retry;
+
+#[cfg(feature = "tower-concurrency")]
+pub mod concurrency;
+
+#[cfg(any(feature = "tower-cookie", feature = "tower-backoff"))]
+mod http_date;
+
+#[cfg(any(feature = "tower-rate", feature = "tower-concurrency"))]
+mod key;
+
+#[cfg(any(feature = "tower-redirect", feature = "tower-retry"))]
+mod replay;
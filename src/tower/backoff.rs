@@ -0,0 +1,38 @@
+//! Adaptive backoff middleware.
+//!
+//! Slows down automatically when a server pushes back, instead of hammering it
+//! at a fixed rate.
+//!
+//! # Quick Start
+//!
+//! ```no_run
+//! use wreq::Client;
+//! use wreq_util::tower::backoff::BackoffLayer;
+//!
+//! let client = Client::builder().layer(BackoffLayer::new()).build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! # How it works
+//!
+//! - A `429 Too Many Requests` or `503 Service Unavailable` response starts a backoff for its
+//!   host, which doubles with every further pushback up to a maximum.
+//! - The next request to the host waits for `Retry-After` (delay seconds or an HTTP date) when the
+//!   response has one, and for the backoff otherwise.
+//! - An exhausted quota in `RateLimit-Remaining`/`RateLimit-Reset` or their `X-RateLimit-*`
+//!   variants holds requests until the reset, whatever the status.
+//! - While backing off, requests to the host are spaced by the backoff, which halves over time
+//!   until requests flow freely again.
+//!
+//! # Notes
+//!
+//! - Responses are returned as-is; pair with a retry layer to resend rejected requests
+//! - Hosts are tracked separately, so one slow host does not hold back others
+//! - The backoff state is shared by all clones of a layer
+
+mod future;
+mod layer;
+mod service;
+mod state;
+
+pub use self::{future::ResponseFuture, layer::BackoffLayer, service::Backoff};
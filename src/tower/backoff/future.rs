@@ -0,0 +1,79 @@
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use http::Response;
+use pin_project_lite::pin_project;
+use tower::{BoxError, Service};
+
+use super::state::Hosts;
+use crate::tower::delay;
+
+pin_project! {
+    /// Response future for [`Backoff`].
+    ///
+    /// Waits out the backoff of the request host, then records whether the
+    /// response asks the client to slow down.
+    ///
+    /// [`Backoff`]: super::Backoff
+    pub struct ResponseFuture<S, Req>
+    where
+        S: Service<Req>,
+    {
+        #[pin]
+        response: delay::DeferredFuture<S, Req>,
+        hosts: Hosts,
+        host: Option<String>,
+    }
+}
+
+impl<S, Req> ResponseFuture<S, Req>
+where
+    S: Service<Req>,
+{
+    // Create a new [`ResponseFuture`]
+    #[inline]
+    pub(crate) fn new(
+        response: delay::DeferredFuture<S, Req>,
+        hosts: Hosts,
+        host: Option<String>,
+    ) -> Self {
+        ResponseFuture {
+            response,
+            hosts,
+            host,
+        }
+    }
+}
+
+impl<S, Req, B> Future for ResponseFuture<S, Req>
+where
+    S: Service<Req, Response = Response<B>>,
+    S::Error: Into<BoxError>,
+{
+    type Output = Result<Response<B>, BoxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let response = ready!(this.response.poll(cx))?;
+        if let Some(host) = this.host.take() {
+            this.hosts
+                .observe(host, response.status(), response.headers());
+        }
+        Poll::Ready(Ok(response))
+    }
+}
+
+impl<S, Req> fmt::Debug for ResponseFuture<S, Req>
+where
+    S: Service<Req>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseFuture")
+            .field("response", &self.response)
+            .field("host", &self.host)
+            .finish()
+    }
+}
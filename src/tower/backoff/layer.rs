@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use tower::Layer;
+
+use super::{
+    service::Backoff,
+    state::{Config, Hosts},
+};
+use crate::tower::timer::{Time, Timer};
+
+/// A Tower [`Layer`] that backs off from hosts answering with `429 Too Many
+/// Requests` or `503 Service Unavailable`.
+///
+/// The backoff state is shared by all clones of the layer and the services it
+/// creates.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use wreq::Client;
+/// use wreq_util::tower::backoff::BackoffLayer;
+///
+/// let client = Client::builder()
+///     .layer(BackoffLayer::new().max(Duration::from_secs(120)))
+///     .build()?;
+/// # Ok::<(), wreq::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct BackoffLayer {
+    hosts: Hosts,
+}
+
+// ===== impl BackoffLayer =====
+
+impl BackoffLayer {
+    /// Create a new [`BackoffLayer`] starting at 1 second, capped at 60
+    /// seconds and halving every 30 seconds.
+    pub fn new() -> Self {
+        BackoffLayer {
+            hosts: Hosts::new(Config {
+                base: Duration::from_secs(1),
+                max: Duration::from_secs(60),
+                half_life: Duration::from_secs(30),
+            }),
+        }
+    }
+
+    /// Set the backoff after the first pushback from a host. It doubles with
+    /// every further pushback.
+    pub fn base(mut self, base: Duration) -> Self {
+        self.hosts.config.base = base;
+        self
+    }
+
+    /// Cap the backoff, including waits requested by the server.
+    pub fn max(mut self, max: Duration) -> Self {
+        self.hosts.config.max = max;
+        self
+    }
+
+    /// Set how fast the backoff recovers: it halves every `half_life` without
+    /// pushback, and ends once it drops below the base.
+    pub fn half_life(mut self, half_life: Duration) -> Self {
+        self.hosts.config.half_life = half_life;
+        self
+    }
+
+    /// Measure and sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.hosts.time = Time::new(timer);
+        self
+    }
+}
+
+impl Default for BackoffLayer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for BackoffLayer {
+    type Service = Backoff<S>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        Backoff::new(inner, self.hosts.clone())
+    }
+}
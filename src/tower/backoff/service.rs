@@ -0,0 +1,54 @@
+use std::{
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use http::{Request, Response};
+use tower::{BoxError, Service};
+
+use super::{future::ResponseFuture, state::Hosts};
+use crate::tower::delay::{self, take_ready};
+
+/// A Tower [`Service`] that slows down requests to hosts that push back.
+///
+/// Created by [`BackoffLayer`](super::BackoffLayer).
+#[derive(Clone, Debug)]
+pub struct Backoff<S> {
+    inner: S,
+    hosts: Hosts,
+}
+
+// ===== impl Backoff =====
+
+impl<S> Backoff<S> {
+    #[inline]
+    pub(crate) fn new(inner: S, hosts: Hosts) -> Self {
+        Backoff { inner, hosts }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for Backoff<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone,
+    S::Error: Into<BoxError>,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = ResponseFuture<S, Request<ReqBody>>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let host = req.uri().host().map(str::to_ascii_lowercase);
+        let delay = host
+            .as_deref()
+            .map_or(Duration::ZERO, |host| self.hosts.reserve(host));
+
+        let response =
+            delay::DeferredFuture::new(take_ready(&mut self.inner), req, delay, &self.hosts.time);
+        ResponseFuture::new(response, self.hosts.clone(), host)
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant, SystemTime},
+};
+
+use http::{
+    HeaderMap, StatusCode,
+    header::{DATE, RETRY_AFTER},
+};
+
+use crate::tower::{http_date::parse_http_date, timer::Time};
+
+/// `X-RateLimit-Reset` values above this are Unix timestamps rather than
+/// delays in seconds.
+const EPOCH_THRESHOLD: f64 = 1_000_000_000.0;
+
+/// Backoff settings, copied into every service created by the layer.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Config {
+    pub(crate) base: Duration,
+    pub(crate) max: Duration,
+    pub(crate) half_life: Duration,
+}
+
+/// Per-host backoff state, shared between clones of a layer and its services.
+#[derive(Clone, Debug)]
+pub(crate) struct Hosts {
+    pub(crate) config: Config,
+    pub(crate) time: Time,
+    slots: Arc<Mutex<HashMap<String, Slot>>>,
+}
+
+#[derive(Debug)]
+struct Slot {
+    /// No request is dispatched to the host before this instant.
+    until: Instant,
+    /// The spacing between requests while the host is backing off.
+    backoff: Duration,
+    /// When `backoff` was last doubled or halved.
+    updated: Instant,
+}
+
+// ===== impl Slot =====
+
+impl Slot {
+    fn new(now: Instant) -> Self {
+        Slot {
+            until: now,
+            backoff: Duration::ZERO,
+            updated: now,
+        }
+    }
+
+    /// Halve the backoff for every `half_life` elapsed since it last changed.
+    fn decay(&mut self, now: Instant, config: &Config) {
+        if self.backoff.is_zero() || config.half_life.is_zero() {
+            return;
+        }
+
+        let halvings = now.duration_since(self.updated).as_nanos() / config.half_life.as_nanos();
+        if halvings == 0 {
+            return;
+        }
+
+        match u32::try_from(halvings)
+            .ok()
+            .filter(|halvings| *halvings < 32)
+        {
+            Some(halvings) => {
+                self.backoff /= 1 << halvings;
+                self.updated += config.half_life * halvings;
+            }
+            None => self.backoff = Duration::ZERO,
+        }
+        if self.backoff < config.base {
+            self.backoff = Duration::ZERO;
+        }
+    }
+
+    fn is_idle(&self, now: Instant) -> bool {
+        self.backoff.is_zero() && self.until <= now
+    }
+}
+
+// ===== impl Hosts =====
+
+impl Hosts {
+    pub(crate) fn new(config: Config) -> Self {
+        Hosts {
+            config,
+            time: Time::default(),
+            slots: Arc::default(),
+        }
+    }
+
+    /// Reserve the next slot for a request to `host` and return how long the
+    /// request must wait.
+    pub(crate) fn reserve(&self, host: &str) -> Duration {
+        let now = self.time.now();
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let Some(slot) = slots.get_mut(host) else {
+            return Duration::ZERO;
+        };
+
+        slot.decay(now, &self.config);
+        if slot.is_idle(now) {
+            slots.remove(host);
+            return Duration::ZERO;
+        }
+
+        let wait = slot.until.saturating_duration_since(now);
+        if let Some(until) = slot.until.max(now).checked_add(slot.backoff) {
+            slot.until = until;
+        }
+        wait
+    }
+
+    /// Update the state of `host` from a response it sent.
+    pub(crate) fn observe(&self, host: String, status: StatusCode, headers: &HeaderMap) {
+        let pushback =
+            status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE;
+        let hint = pushback
+            .then(|| retry_after(headers))
+            .flatten()
+            .or_else(|| rate_limit_reset(headers));
+        if !pushback && hint.is_none() {
+            return;
+        }
+
+        let now = self.time.now();
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        let slot = slots.entry(host).or_insert_with(|| Slot::new(now));
+
+        slot.decay(now, &self.config);
+        if pushback {
+            slot.backoff = if slot.backoff.is_zero() {
+                self.config.base
+            } else {
+                slot.backoff.saturating_mul(2)
+            }
+            .min(self.config.max);
+            slot.updated = now;
+        }
+
+        // Hints come from the server, so they are capped like the backoff.
+        let wait = hint.unwrap_or(slot.backoff).min(self.config.max);
+        if let Some(until) = now.checked_add(wait) {
+            slot.until = slot.until.max(until);
+        }
+    }
+}
+
+/// The time the server sent the response, falling back to the local clock.
+fn server_time(headers: &HeaderMap) -> SystemTime {
+    headers
+        .get(DATE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_http_date)
+        .unwrap_or_else(SystemTime::now)
+}
+
+/// Parse `Retry-After` as either delay seconds or an HTTP date.
+///
+/// Dates are measured against the response `Date` so that clock skew between
+/// client and server does not matter.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        return value.parse().ok().map(Duration::from_secs);
+    }
+
+    let date = parse_http_date(value)?;
+    Some(
+        date.duration_since(server_time(headers))
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Parse the reset time of an exhausted `RateLimit-*` or `X-RateLimit-*` quota.
+fn rate_limit_reset(headers: &HeaderMap) -> Option<Duration> {
+    let number = |name: &str| {
+        headers
+            .get(name)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite() && *value >= 0.0)
+    };
+
+    [
+        ("ratelimit-remaining", "ratelimit-reset"),
+        ("x-ratelimit-remaining", "x-ratelimit-reset"),
+    ]
+    .into_iter()
+    .find_map(|(remaining, reset)| {
+        if number(remaining)? >= 1.0 {
+            return None;
+        }
+
+        let reset = number(reset)?;
+        if reset < EPOCH_THRESHOLD {
+            return Duration::try_from_secs_f64(reset).ok();
+        }
+
+        let reset = SystemTime::UNIX_EPOCH.checked_add(Duration::try_from_secs_f64(reset).ok()?)?;
+        Some(
+            reset
+                .duration_since(server_time(headers))
+                .unwrap_or(Duration::ZERO),
+        )
+    })
+}
@@ -0,0 +1,35 @@
+//! Per-host concurrency limiting middleware.
+//!
+//! Caps the number of requests in flight to each host (or origin, or any
+//! custom key), the way browsers open at most 6 connections per host over
+//! HTTP/1.1. Requests over the limit wait in line, first come first served.
+//!
+//! # Quick Start
+//!
+//! At most 6 requests in flight per host:
+//!
+//! ```no_run
+//! use wreq::Client;
+//! use wreq_util::tower::concurrency::ConcurrencyLimitLayer;
+//!
+//! let client = Client::builder()
+//!     .layer(ConcurrencyLimitLayer::new(6))
+//!     .build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! # Notes
+//!
+//! - A request holds its slot until its response arrives. Reading the body does not count as in
+//!   flight: the client fixes the response body type, so there is nowhere to keep the slot
+//! - Requests without a key (e.g. no host) are not limited
+//! - The limits are shared by all clones of a layer, so build the layer once and reuse it to share
+//!   them between clients
+
+mod future;
+mod layer;
+mod limiter;
+mod service;
+
+pub use self::{future::ResponseFuture, layer::ConcurrencyLimitLayer, service::ConcurrencyLimit};
+pub use super::key::{ByHost, ByOrigin, Global, KeyExtractor};
@@ -0,0 +1,130 @@
+use std::{
+    fmt,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+use tokio::sync::{AcquireError, OwnedSemaphorePermit, Semaphore};
+use tower::{BoxError, Service};
+
+type Acquire = Pin<Box<dyn Future<Output = Result<OwnedSemaphorePermit, AcquireError>> + Send>>;
+
+pin_project! {
+    /// Response future for [`ConcurrencyLimit`].
+    ///
+    /// The request is held until a slot is free and is only then passed to
+    /// the inner service. The slot is released once the response arrives.
+    ///
+    /// [`ConcurrencyLimit`]: super::ConcurrencyLimit
+    pub struct ResponseFuture<S, Req>
+    where
+        S: Service<Req>,
+    {
+        #[pin]
+        state: State<S, Req, S::Future>,
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    enum State<S, Req, F> {
+        Acquiring {
+            acquire: Acquire,
+            // Kept here once acquired, while the service gets ready.
+            permit: Option<OwnedSemaphorePermit>,
+            service: S,
+            req: Option<Req>,
+        },
+        Calling {
+            #[pin]
+            response: F,
+            permit: Option<OwnedSemaphorePermit>,
+        },
+    }
+}
+
+impl<S, Req> ResponseFuture<S, Req>
+where
+    S: Service<Req>,
+{
+    // Create a new [`ResponseFuture`]
+    //
+    // `service` must already be ready. Without a semaphore, or with a free slot,
+    // the request is dispatched right away.
+    pub(crate) fn new(mut service: S, req: Req, semaphore: Option<Arc<Semaphore>>) -> Self {
+        let state = match semaphore {
+            None => State::Calling {
+                response: service.call(req),
+                permit: None,
+            },
+            Some(semaphore) => match semaphore.clone().try_acquire_owned() {
+                Ok(permit) => State::Calling {
+                    response: service.call(req),
+                    permit: Some(permit),
+                },
+                Err(_) => State::Acquiring {
+                    acquire: Box::pin(semaphore.acquire_owned()),
+                    permit: None,
+                    service,
+                    req: Some(req),
+                },
+            },
+        };
+        ResponseFuture { state }
+    }
+}
+
+impl<S, Req> Future for ResponseFuture<S, Req>
+where
+    S: Service<Req>,
+    S::Error: Into<BoxError>,
+{
+    type Output = Result<S::Response, BoxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Acquiring {
+                    acquire,
+                    permit,
+                    service,
+                    req,
+                } => {
+                    if permit.is_none() {
+                        *permit = Some(ready!(acquire.as_mut().poll(cx))?);
+                    }
+                    ready!(service.poll_ready(cx)).map_err(Into::into)?;
+                    let req = req.take().expect("polled after completion");
+                    let response = service.call(req);
+                    let permit = permit.take();
+                    this.state.set(State::Calling { response, permit });
+                }
+                StateProj::Calling { response, permit } => {
+                    let res = ready!(response.poll(cx)).map_err(Into::into)?;
+                    // The response body type is fixed by the client, so the
+                    // slot can't follow the body and is released here.
+                    drop(permit.take());
+                    return Poll::Ready(Ok(res));
+                }
+            }
+        }
+    }
+}
+
+impl<S, Req> fmt::Debug for ResponseFuture<S, Req>
+where
+    S: Service<Req>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.state {
+            State::Acquiring { .. } => "Acquiring",
+            State::Calling { .. } => "Calling",
+        };
+        f.debug_struct("ResponseFuture")
+            .field("state", &state)
+            .finish()
+    }
+}
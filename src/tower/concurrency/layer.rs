@@ -0,0 +1,73 @@
+use tower::Layer;
+
+use super::{ByHost, limiter::Limiter, service::ConcurrencyLimit};
+
+/// A Tower [`Layer`] that caps the requests in flight per key, by default the
+/// request host.
+///
+/// Requests over the limit wait for a slot rather than failing, in the order
+/// they were made. The limits are shared by all clones of the layer and the
+/// services it creates.
+///
+/// # Example
+///
+/// ```no_run
+/// use wreq::Client;
+/// use wreq_util::tower::concurrency::{ByOrigin, ConcurrencyLimitLayer};
+///
+/// // Like a browser over HTTP/1.1: 6 requests at a time per origin
+/// let client = Client::builder()
+///     .layer(ConcurrencyLimitLayer::new(6).key(ByOrigin))
+///     .build()?;
+/// # Ok::<(), wreq::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ConcurrencyLimitLayer<K = ByHost> {
+    limiter: Limiter,
+    key: K,
+}
+
+// ===== impl ConcurrencyLimitLayer =====
+
+impl ConcurrencyLimitLayer {
+    /// Allow `max` requests in flight for each host at a time.
+    ///
+    /// A `max` of zero is treated as one.
+    pub fn new(max: usize) -> Self {
+        ConcurrencyLimitLayer {
+            limiter: Limiter::new(max),
+            key: ByHost,
+        }
+    }
+}
+
+impl<K> ConcurrencyLimitLayer<K> {
+    /// Choose what requests are grouped by, e.g. [`ByOrigin`](super::ByOrigin)
+    /// or a closure.
+    pub fn key<K2>(self, key: K2) -> ConcurrencyLimitLayer<K2> {
+        ConcurrencyLimitLayer {
+            limiter: self.limiter,
+            key,
+        }
+    }
+}
+
+impl Default for ConcurrencyLimitLayer {
+    /// 6 requests in flight per host, like browsers over HTTP/1.1.
+    #[inline]
+    fn default() -> Self {
+        Self::new(6)
+    }
+}
+
+impl<S, K> Layer<S> for ConcurrencyLimitLayer<K>
+where
+    K: Clone,
+{
+    type Service = ConcurrencyLimit<S, K>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        ConcurrencyLimit::new(inner, self.limiter.clone(), self.key.clone())
+    }
+}
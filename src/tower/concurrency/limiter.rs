@@ -0,0 +1,55 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use tokio::sync::Semaphore;
+
+/// Idle keys are swept after this many acquisitions.
+const SWEEP_INTERVAL: u32 = 1024;
+
+/// In-flight limits per key, shared between clones of a layer and its services.
+#[derive(Clone, Debug)]
+pub(crate) struct Limiter {
+    max: usize,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    slots: HashMap<String, Arc<Semaphore>>,
+    acquisitions: u32,
+}
+
+// ===== impl Limiter =====
+
+impl Limiter {
+    pub(crate) fn new(max: usize) -> Self {
+        Limiter {
+            max: max.clamp(1, Semaphore::MAX_PERMITS),
+            state: Arc::default(),
+        }
+    }
+
+    /// Return the semaphore guarding `key`.
+    ///
+    /// Waiters on a semaphore are served in the order they started waiting.
+    pub(crate) fn semaphore(&self, key: String) -> Arc<Semaphore> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        state.acquisitions += 1;
+        if state.acquisitions >= SWEEP_INTERVAL {
+            state.acquisitions = 0;
+            // Only drop semaphores nobody holds or waits on.
+            state.slots.retain(|_, semaphore| {
+                Arc::strong_count(semaphore) > 1 || semaphore.available_permits() < self.max
+            });
+        }
+
+        state
+            .slots
+            .entry(key)
+            .or_insert_with(|| Arc::new(Semaphore::new(self.max)))
+            .clone()
+    }
+}
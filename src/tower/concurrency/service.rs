@@ -0,0 +1,57 @@
+use std::task::{Context, Poll};
+
+use http::{Request, Response};
+use tower::{BoxError, Service};
+
+use super::{KeyExtractor, future::ResponseFuture, limiter::Limiter};
+
+/// A Tower [`Service`] that caps the requests in flight per key.
+///
+/// Created by [`ConcurrencyLimitLayer`](super::ConcurrencyLimitLayer).
+#[derive(Clone, Debug)]
+pub struct ConcurrencyLimit<S, K> {
+    inner: S,
+    limiter: Limiter,
+    key: K,
+}
+
+// ===== impl ConcurrencyLimit =====
+
+impl<S, K> ConcurrencyLimit<S, K> {
+    #[inline]
+    pub(crate) fn new(inner: S, limiter: Limiter, key: K) -> Self {
+        ConcurrencyLimit {
+            inner,
+            limiter,
+            key,
+        }
+    }
+}
+
+impl<S, K, ReqBody, ResBody> Service<Request<ReqBody>> for ConcurrencyLimit<S, K>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone,
+    S::Error: Into<BoxError>,
+    K: KeyExtractor<Request<ReqBody>>,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = ResponseFuture<S, Request<ReqBody>>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let semaphore = self
+            .key
+            .extract(&req)
+            .map(|key| self.limiter.semaphore(key));
+
+        // The readied service is only called once a slot is free.
+        let clone = self.inner.clone();
+        let inner = std::mem::replace(&mut self.inner, clone);
+        ResponseFuture::new(inner, req, semaphore)
+    }
+}
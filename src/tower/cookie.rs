@@ -0,0 +1,70 @@
+//! Browser-like cookie middleware.
+//!
+//! Stores cookies set by responses and sends them back on later requests,
+//! serializing the `Cookie` header the way the emulated browser does. Together
+//! with [`Emulation`], this removes one of the remaining differences between
+//! a client and a real browser once TLS and headers are matched.
+//!
+//! # Quick Start
+//!
+//! ```no_run
+//! use wreq::Client;
+//! use wreq_util::tower::cookie::CookieManagerLayer;
+//!
+//! let client = Client::builder()
+//!     .layer(CookieManagerLayer::new())
+//!     .build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! # Per-Browser Serialization
+//!
+//! Chrome splits cookies into separate header fields over HTTP/2, which only
+//! works once the protocol is known. Install a [`CookieProvider`] as the
+//! client's cookie store instead of the layer to serialize per [`CookieStyle`]:
+//!
+//! ```no_run
+//! use wreq::Client;
+//! use wreq_util::tower::cookie::{CookieProvider, CookieStyle};
+//!
+//! let client = Client::builder()
+//!     .cookie_provider(CookieProvider::new(CookieStyle::Chrome))
+//!     .build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! # Sharing a Session
+//!
+//! A [`CookieJar`] is a cheap, shared handle. Pass the same jar to several
+//! layers or providers to share one session between clients, or keep a handle
+//! to inspect and clear it:
+//!
+//! ```ignore
+//! let jar = CookieJar::new();
+//! let layer = CookieManagerLayer::with_jar(jar.clone());
+//! ```
+//!
+//! # Notes
+//!
+//! - Cookies are ordered as in RFC 6265: longer paths first, then by creation time
+//! - [`CookieManagerLayer`] always joins cookies into a single `Cookie` header, since the
+//!   protocol is only negotiated after the layer runs, and separate fields on an HTTP/1.1
+//!   connection are read by most servers as just the first cookie
+//! - Requests that already carry a `Cookie` header are left untouched
+//! - Use either the layer or the provider, not both, and no other `wreq` cookie store
+//!
+//! [`Emulation`]: https://docs.rs/wreq-util/latest/wreq_util/struct.Emulation.html
+
+mod future;
+mod jar;
+mod layer;
+mod provider;
+mod service;
+
+pub use self::{
+    future::ResponseFuture,
+    jar::CookieJar,
+    layer::CookieManagerLayer,
+    provider::{CookieProvider, CookieStyle},
+    service::CookieManager,
+};
@@ -0,0 +1,46 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use http::{Response, Uri, header::SET_COOKIE};
+use pin_project_lite::pin_project;
+
+use super::CookieJar;
+
+pin_project! {
+    /// Response future for [`CookieManager`].
+    ///
+    /// [`CookieManager`]: super::CookieManager
+    #[derive(Debug)]
+    pub struct ResponseFuture<F> {
+        #[pin]
+        response: F,
+        jar: CookieJar,
+        uri: Uri,
+    }
+}
+
+impl<F> ResponseFuture<F> {
+    // Create a new [`ResponseFuture`]
+    #[inline]
+    pub(crate) fn new(response: F, jar: CookieJar, uri: Uri) -> Self {
+        ResponseFuture { response, jar, uri }
+    }
+}
+
+impl<F, B, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<B>, E>>,
+{
+    type Output = Result<Response<B>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let response = ready!(this.response.poll(cx))?;
+        for set_cookie in response.headers().get_all(SET_COOKIE) {
+            this.jar.store(this.uri, set_cookie);
+        }
+        Poll::Ready(Ok(response))
+    }
+}
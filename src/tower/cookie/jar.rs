@@ -0,0 +1,268 @@
+use std::{
+    net::IpAddr,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime},
+};
+
+use http::{HeaderValue, Uri};
+
+use crate::tower::http_date::parse_http_date;
+
+/// A shared cookie store for a single browsing session.
+///
+/// Cloning a [`CookieJar`] is cheap and yields a handle to the same store, so a
+/// jar can be shared between several [`CookieManagerLayer`]s or inspected from
+/// outside the client.
+///
+/// [`CookieManagerLayer`]: super::CookieManagerLayer
+#[derive(Clone, Debug, Default)]
+pub struct CookieJar {
+    store: Arc<Mutex<Store>>,
+}
+
+#[derive(Debug, Default)]
+struct Store {
+    cookies: Vec<StoredCookie>,
+    next_creation: u64,
+}
+
+#[derive(Debug)]
+struct StoredCookie {
+    name: String,
+    value: String,
+    domain: String,
+    host_only: bool,
+    path: String,
+    expires: Option<SystemTime>,
+    secure: bool,
+    creation: u64,
+}
+
+// ===== impl CookieJar =====
+
+impl CookieJar {
+    /// Creates an empty [`CookieJar`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a cookie from a `Set-Cookie` response header received for `uri`.
+    ///
+    /// Cookies that cannot be parsed, whose `Domain` attribute is a top-level
+    /// domain or does not match the request host, or that are marked `Secure`
+    /// but were not received over a secure scheme, are ignored. A cookie whose
+    /// expiry lies in the past removes any stored cookie with the same name,
+    /// domain and path.
+    pub fn store(&self, uri: &Uri, set_cookie: &HeaderValue) {
+        let now = SystemTime::now();
+        let Some(cookie) = parse_set_cookie(uri, set_cookie, now) else {
+            return;
+        };
+
+        let mut store = self.store.lock().unwrap_or_else(PoisonError::into_inner);
+        let creation = match store.cookies.iter().position(|c| c.same_key(&cookie)) {
+            // Replacing a cookie keeps the creation time of the old one.
+            Some(index) => store.cookies.remove(index).creation,
+            None => {
+                store.next_creation += 1;
+                store.next_creation
+            }
+        };
+
+        if !cookie.is_expired(now) {
+            store.cookies.push(StoredCookie { creation, ..cookie });
+        }
+    }
+
+    /// Returns the `name=value` pairs to send to `uri`, in browser order.
+    pub fn cookies(&self, uri: &Uri) -> Vec<String> {
+        let Some(host) = uri.host().map(str::to_ascii_lowercase) else {
+            return Vec::new();
+        };
+        let path = match uri.path() {
+            "" => "/",
+            path => path,
+        };
+        let secure = is_secure(uri);
+        let now = SystemTime::now();
+
+        let mut store = self.store.lock().unwrap_or_else(PoisonError::into_inner);
+        store.cookies.retain(|cookie| !cookie.is_expired(now));
+
+        let mut matched = store
+            .cookies
+            .iter()
+            .filter(|cookie| {
+                (cookie.secure <= secure)
+                    && cookie.matches_domain(&host)
+                    && path_match(path, &cookie.path)
+            })
+            .collect::<Vec<_>>();
+
+        matched.sort_by(|a, b| {
+            b.path
+                .len()
+                .cmp(&a.path.len())
+                .then(a.creation.cmp(&b.creation))
+        });
+
+        matched
+            .into_iter()
+            .map(|cookie| {
+                if cookie.name.is_empty() {
+                    cookie.value.clone()
+                } else {
+                    format!("{}={}", cookie.name, cookie.value)
+                }
+            })
+            .collect()
+    }
+
+    /// Removes all cookies from the jar.
+    pub fn clear(&self) {
+        self.store
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .cookies
+            .clear();
+    }
+}
+
+// ===== impl StoredCookie =====
+
+impl StoredCookie {
+    #[inline]
+    fn same_key(&self, other: &StoredCookie) -> bool {
+        self.name == other.name && self.domain == other.domain && self.path == other.path
+    }
+
+    #[inline]
+    fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    #[inline]
+    fn matches_domain(&self, host: &str) -> bool {
+        if self.host_only {
+            host == self.domain
+        } else {
+            domain_match(host, &self.domain)
+        }
+    }
+}
+
+fn parse_set_cookie(uri: &Uri, set_cookie: &HeaderValue, now: SystemTime) -> Option<StoredCookie> {
+    let host = uri.host()?.to_ascii_lowercase();
+    let set_cookie = set_cookie.to_str().ok()?;
+
+    let mut attributes = set_cookie.split(';');
+    let (name, value) = match attributes.next()?.split_once('=') {
+        Some((name, value)) => (name.trim(), value.trim()),
+        None => ("", set_cookie.split(';').next()?.trim()),
+    };
+
+    if name.is_empty() && value.is_empty() {
+        return None;
+    }
+
+    let mut expires = None;
+    let mut max_age = None;
+    let mut domain = None;
+    let mut path = None;
+    let mut secure = false;
+
+    for attribute in attributes {
+        let (key, val) = match attribute.split_once('=') {
+            Some((key, val)) => (key.trim(), val.trim()),
+            None => (attribute.trim(), ""),
+        };
+
+        if key.eq_ignore_ascii_case("expires") {
+            expires = parse_http_date(val).or(expires);
+        } else if key.eq_ignore_ascii_case("max-age") {
+            max_age = val.parse::<i64>().ok().or(max_age);
+        } else if key.eq_ignore_ascii_case("domain") {
+            let val = val.trim_start_matches('.');
+            if !val.is_empty() {
+                domain = Some(val.to_ascii_lowercase());
+            }
+        } else if key.eq_ignore_ascii_case("path") {
+            path = val.starts_with('/').then(|| val.to_owned());
+        } else if key.eq_ignore_ascii_case("secure") {
+            secure = true;
+        }
+    }
+
+    // Max-Age takes precedence over Expires (RFC 6265 §5.3 step 3).
+    if let Some(max_age) = max_age {
+        expires = match u64::try_from(max_age) {
+            Ok(secs) if secs > 0 => now.checked_add(Duration::from_secs(secs)),
+            _ => Some(SystemTime::UNIX_EPOCH),
+        };
+    }
+
+    // Only secure origins may set Secure cookies (RFC 6265bis §5.7).
+    if secure && !is_secure(uri) {
+        return None;
+    }
+
+    // Without a public suffix list, only single-label domains such as `com`
+    // are recognized as public suffixes. One is accepted solely as a host-only
+    // cookie for that exact host (RFC 6265bis §5.7).
+    let (domain, host_only) = match domain {
+        Some(domain) if !domain.contains('.') => {
+            if domain != host {
+                return None;
+            }
+            (host, true)
+        }
+        Some(domain) if domain_match(&host, &domain) => (domain, false),
+        Some(_) => return None,
+        None => (host, true),
+    };
+
+    Some(StoredCookie {
+        name: name.to_owned(),
+        value: value.to_owned(),
+        domain,
+        host_only,
+        path: path.unwrap_or_else(|| default_path(uri.path())),
+        expires,
+        secure,
+        creation: 0,
+    })
+}
+
+#[inline]
+fn is_secure(uri: &Uri) -> bool {
+    matches!(uri.scheme_str(), Some("https" | "wss"))
+}
+
+/// Domain matching as defined by RFC 6265 §5.1.3.
+fn domain_match(host: &str, domain: &str) -> bool {
+    if host == domain {
+        return true;
+    }
+
+    host.parse::<IpAddr>().is_err()
+        && host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// Path matching as defined by RFC 6265 §5.1.4.
+fn path_match(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || request_path
+            .strip_prefix(cookie_path)
+            .is_some_and(|rest| cookie_path.ends_with('/') || rest.starts_with('/'))
+}
+
+/// Default cookie path as defined by RFC 6265 §5.1.4.
+fn default_path(request_path: &str) -> String {
+    match request_path.rfind('/') {
+        Some(0) | None => "/".to_owned(),
+        Some(index) => request_path[..index].to_owned(),
+    }
+}
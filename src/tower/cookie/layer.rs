@@ -0,0 +1,44 @@
+use tower::Layer;
+
+use super::{CookieJar, service::CookieManager};
+
+/// A Tower [`Layer`] that stores response cookies and sends them back like a browser.
+///
+/// Each layer owns one [`CookieJar`], so every client built with it shares a
+/// single browsing session. Use [`CookieManagerLayer::with_jar`] to share or
+/// inspect the jar from outside the client.
+#[derive(Clone, Debug, Default)]
+pub struct CookieManagerLayer {
+    jar: CookieJar,
+}
+
+// ===== impl CookieManagerLayer =====
+
+impl CookieManagerLayer {
+    /// Create a new [`CookieManagerLayer`] with an empty jar.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_jar(CookieJar::new())
+    }
+
+    /// Create a new [`CookieManagerLayer`] backed by an existing jar.
+    #[inline]
+    pub fn with_jar(jar: CookieJar) -> Self {
+        Self { jar }
+    }
+
+    /// Returns the jar backing this layer.
+    #[inline]
+    pub fn jar(&self) -> &CookieJar {
+        &self.jar
+    }
+}
+
+impl<S> Layer<S> for CookieManagerLayer {
+    type Service = CookieManager<S>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        CookieManager::new(inner, self.jar.clone())
+    }
+}
@@ -0,0 +1,91 @@
+use http::{HeaderValue, Uri, Version};
+use wreq::cookie::{CookieStore, Cookies};
+
+use super::CookieJar;
+
+/// Selects how the `Cookie` request header is serialized.
+///
+/// All supported browsers order cookies as described by RFC 6265 §5.4: cookies
+/// with longer paths come first, and cookies with equal path lengths are
+/// ordered by creation time. They differ in how the list is put on the wire.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CookieStyle {
+    /// Chrome, Edge and Opera.
+    ///
+    /// Over HTTP/2 every cookie is sent as its own `cookie` header field so
+    /// each crumb can be indexed separately by HPACK. Over HTTP/1 all cookies
+    /// are joined into a single header.
+    #[default]
+    Chrome,
+
+    /// Firefox, which always joins all cookies into a single header.
+    Firefox,
+
+    /// Safari, which always joins all cookies into a single header.
+    Safari,
+}
+
+/// A `wreq` cookie store that serializes the `Cookie` header per [`CookieStyle`].
+///
+/// Unlike [`CookieManagerLayer`], which runs before the connection is picked,
+/// the client consults a cookie store once it knows whether the request goes
+/// out over HTTP/1.1 or HTTP/2. That makes it the place to split cookies the
+/// way Chrome does. Install it with `ClientBuilder::cookie_provider`.
+///
+/// [`CookieManagerLayer`]: super::CookieManagerLayer
+#[derive(Clone, Debug, Default)]
+pub struct CookieProvider {
+    jar: CookieJar,
+    style: CookieStyle,
+}
+
+// ===== impl CookieProvider =====
+
+impl CookieProvider {
+    /// Create a new [`CookieProvider`] with an empty jar.
+    #[inline]
+    pub fn new(style: CookieStyle) -> Self {
+        Self::with_jar(CookieJar::new(), style)
+    }
+
+    /// Create a new [`CookieProvider`] backed by an existing jar.
+    #[inline]
+    pub fn with_jar(jar: CookieJar, style: CookieStyle) -> Self {
+        Self { jar, style }
+    }
+
+    /// Returns the jar backing this provider.
+    #[inline]
+    pub fn jar(&self) -> &CookieJar {
+        &self.jar
+    }
+}
+
+impl CookieStore for CookieProvider {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, uri: &Uri) {
+        for set_cookie in cookie_headers {
+            self.jar.store(uri, set_cookie);
+        }
+    }
+
+    fn cookies(&self, uri: &Uri, version: Version) -> Cookies {
+        let cookies = self.jar.cookies(uri);
+        if cookies.is_empty() {
+            return Cookies::Empty;
+        }
+
+        if self.style == CookieStyle::Chrome && version >= Version::HTTP_2 {
+            let values = cookies
+                .into_iter()
+                .filter_map(|cookie| HeaderValue::try_from(cookie).ok())
+                .collect();
+            return Cookies::Uncompressed(values);
+        }
+
+        match HeaderValue::try_from(cookies.join("; ")) {
+            Ok(value) => Cookies::Compressed(value),
+            Err(_) => Cookies::Empty,
+        }
+    }
+}
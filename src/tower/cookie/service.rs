@@ -0,0 +1,54 @@
+use std::task::{Context, Poll};
+
+use http::{HeaderValue, Request, Response, header::COOKIE};
+use tower::Service;
+
+use super::{CookieJar, future::ResponseFuture};
+
+/// A Tower [`Service`] that attaches stored cookies to requests and stores
+/// cookies set by responses.
+///
+/// Requests that already carry a `Cookie` header are sent unchanged.
+#[derive(Clone, Debug)]
+pub struct CookieManager<S> {
+    inner: S,
+    jar: CookieJar,
+}
+
+// ===== impl CookieManager =====
+
+impl<S> CookieManager<S> {
+    /// Create a new [`CookieManager`] service wrapping the given inner service.
+    #[inline]
+    pub fn new(inner: S, jar: CookieJar) -> Self {
+        CookieManager { inner, jar }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for CookieManager<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        if !req.headers().contains_key(COOKIE) {
+            let cookies = self.jar.cookies(req.uri());
+            if !cookies.is_empty() {
+                if let Ok(value) = HeaderValue::try_from(cookies.join("; ")) {
+                    req.headers_mut().insert(COOKIE, value);
+                }
+            }
+        }
+
+        let uri = req.uri().clone();
+        ResponseFuture::new(self.inner.call(req), self.jar.clone(), uri)
+    }
+}
@@ -1,6 +1,6 @@
 //! Request delay middleware.
 //!
-//! Adds configurable delays before HTTP requests — useful for rate limiting,
+//! Adds configurable delays to HTTP requests — useful for rate limiting,
 //! testing under slow network conditions, or just being polite to APIs.
 //!
 //! # Quick Start
@@ -31,6 +31,21 @@
 //! # Ok::<(), wreq::Error>(())
 //! ```
 //!
+//! Human-like think time, heavy-tailed around a 2-second median:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use wreq::Client;
+//! use wreq_util::tower::delay::{Distribution, ThinkTimeLayer};
+//!
+//! let client = Client::builder()
+//!     .layer(ThinkTimeLayer::new(
+//!         Distribution::log_normal(Duration::from_secs(2), 0.6).max(Duration::from_secs(30)),
+//!     ))
+//!     .build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
 //! # Conditional Delays
 //!
 //! Use `.when()` to apply delays only to matching requests:
@@ -45,26 +60,63 @@
 //!     .when(|req: &http::Request<_>| req.uri().path().starts_with("/api"))
 //! ```
 //!
+//! # Delay Policies
+//!
+//! Use [`PolicyDelayLayer`] with a [`DelayPolicy`] to pick the delay per request, consult
+//! shared async state, or combine conditions with `and`/`or`/`not`:
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use http::Request;
+//! use wreq_util::tower::delay::{
+//!     PolicyDelayLayer,
+//!     policy::{DelayPolicyExt, jitter, when},
+//! };
+//!
+//! // Jittered delays for API requests, except health checks
+//! let api = when(|req: &Request<_>| req.uri().path().starts_with("/api"));
+//! let health = when(|req: &Request<_>| req.uri().path() == "/api/health");
+//! PolicyDelayLayer::new(api.and(health.not()).and(jitter(Duration::from_secs(1), 0.2)))
+//! ```
+//!
 //! # Notes
 //!
 //! - Delays are async and won't block the runtime
+//! - Only [`PolicyDelayLayer`] delays before dispatch. [`DelayLayer`], [`JitterDelayLayer`] and
+//!   [`ThinkTimeLayer`] send the request right away and only hold back its response, so the
+//!   server sees no delay. To space out requests on the wire, use e.g.
+//!   `PolicyDelayLayer::new(Duration::from_secs(1))`, which needs a `Clone` inner service
 //! - Not a substitute for proper rate limiters — servers can still see timing patterns
 //! - Keep delays short in hot paths
+//! - Pass a seeded [`Rng`](crate::Rng) to `.rng()` on a layer to make its delays reproducible
+//! - Delays work under both `tokio-rt` and `compio-rt`; see [`timer`](crate::tower::timer) to
+//!   pick the runtime timer explicitly
 
+mod distribution;
 mod future;
 mod layer;
+pub mod policy;
 mod service;
 
 use std::time::Duration;
 
+use crate::rand::Rng;
+
 pub use self::{
-    future::ResponseFuture,
-    layer::{DelayLayer, DelayLayerWith, JitterDelayLayer, JitterDelayLayerWith},
-    service::{Delay, DelayWith, JitterDelay, JitterDelayWith},
+    distribution::Distribution,
+    future::{DeferredFuture, PolicyResponseFuture, ResponseFuture},
+    layer::{
+        DelayLayer, DelayLayerWith, JitterDelayLayer, JitterDelayLayerWith, PolicyDelayLayer,
+        ThinkTimeLayer, ThinkTimeLayerWith,
+    },
+    policy::DelayPolicy,
+    service::{
+        Delay, DelayWith, JitterDelay, JitterDelayWith, PolicyDelay, ThinkTime, ThinkTimeWith,
+    },
 };
 
 /// Compute a randomized duration in `[base * (1 - pct), base * (1 + pct)]`.
-fn jittered_duration(base: Duration, pct: f64) -> Duration {
+pub(crate) fn jittered_duration(base: Duration, pct: f64, rng: &Rng) -> Duration {
     let jitter = base.mul_f64(pct);
     let low = base.saturating_sub(jitter);
     let high = base.saturating_add(jitter);
@@ -73,26 +125,12 @@ fn jittered_duration(base: Duration, pct: f64) -> Duration {
         return base;
     }
 
-    // Generate pseudo-random value using multiple entropy sources
-    let time_entropy = {
-        use std::time::SystemTime;
-        SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .map(|d| d.as_nanos() as u64)
-            .unwrap_or(0)
-    };
-
-    // Use stack address as additional entropy source
-    let addr_entropy = (&time_entropy as *const u64 as u64).wrapping_mul(0x517cc1b727220a95);
-
-    // Mix entropy sources with a simple but effective hash
-    let mixed = time_entropy
-        .wrapping_add(addr_entropy)
-        .wrapping_mul(0x9e3779b97f4a7c15);
-
-    // Convert to fraction in [0, 1)
-    let frac = (mixed as f64) / (u64::MAX as f64);
+    low + (high - low).mul_f64(rng.next_f64())
+}
 
-    let span = (high - low).as_secs_f64();
-    low + Duration::from_secs_f64(span * frac)
+/// Take the service that was driven to readiness, leaving a clone in its place.
+#[inline]
+pub(crate) fn take_ready<S: Clone>(inner: &mut S) -> S {
+    let clone = inner.clone();
+    std::mem::replace(inner, clone)
 }
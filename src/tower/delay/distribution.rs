@@ -0,0 +1,152 @@
+use std::{f64::consts::TAU, sync::Arc, time::Duration};
+
+use crate::rand::Rng;
+
+/// A distribution of think times between requests.
+///
+/// Real users don't wait a fixed time between requests: most gaps are short,
+/// with a long tail of pauses. Sample delays from a distribution that matches
+/// the traffic being emulated so the timing doesn't stand out.
+///
+/// Every sample is clamped to `[min, max]`, which defaults to `[0, ∞)`.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use wreq_util::tower::delay::Distribution;
+///
+/// // Median of 2s, mostly between 1s and 4s, never more than 30s
+/// let think_time = Distribution::log_normal(Duration::from_secs(2), 0.5)
+///     .max(Duration::from_secs(30));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Distribution {
+    kind: Kind,
+    min: Duration,
+    max: Duration,
+}
+
+#[derive(Clone, Debug)]
+enum Kind {
+    Uniform { low: f64, high: f64 },
+    Normal { mean: f64, std_dev: f64 },
+    LogNormal { mu: f64, sigma: f64 },
+    Exponential { mean: f64 },
+    // Sorted samples in seconds.
+    Empirical(Arc<[f64]>),
+}
+
+// ===== impl Distribution =====
+
+impl Distribution {
+    /// Delays uniformly distributed in `[low, high]`.
+    pub fn uniform(low: Duration, high: Duration) -> Self {
+        Self::new(Kind::Uniform {
+            low: low.as_secs_f64(),
+            high: high.as_secs_f64(),
+        })
+    }
+
+    /// Normally distributed delays. Negative samples are clamped to `min`.
+    pub fn normal(mean: Duration, std_dev: Duration) -> Self {
+        Self::new(Kind::Normal {
+            mean: mean.as_secs_f64(),
+            std_dev: std_dev.as_secs_f64(),
+        })
+    }
+
+    /// Log-normally distributed delays, a heavy-tailed fit for human think
+    /// times.
+    ///
+    /// Half of the delays are below `median`; `sigma` is the standard
+    /// deviation of their logarithm and controls how long the tail is.
+    pub fn log_normal(median: Duration, sigma: f64) -> Self {
+        Self::new(Kind::LogNormal {
+            mu: median.as_secs_f64().ln(),
+            sigma: sigma.max(0.0),
+        })
+    }
+
+    /// Exponentially distributed delays, i.e. requests arriving as a Poisson
+    /// process at an average rate of one per `mean`.
+    pub fn exponential(mean: Duration) -> Self {
+        Self::new(Kind::Exponential {
+            mean: mean.as_secs_f64(),
+        })
+    }
+
+    /// Delays following the empirical distribution of recorded gaps, e.g.
+    /// taken from real browsing sessions.
+    ///
+    /// Samples are interpolated between the recorded values, so the result is
+    /// a smoothed version of their histogram. Without any recorded gap, every
+    /// delay is `min`.
+    pub fn empirical<I>(samples: I) -> Self
+    where
+        I: IntoIterator<Item = Duration>,
+    {
+        let mut samples: Vec<f64> = samples.into_iter().map(|d| d.as_secs_f64()).collect();
+        samples.sort_by(f64::total_cmp);
+        Self::new(Kind::Empirical(samples.into()))
+    }
+
+    fn new(kind: Kind) -> Self {
+        Distribution {
+            kind,
+            min: Duration::ZERO,
+            max: Duration::MAX,
+        }
+    }
+
+    /// Never return a delay shorter than `min`.
+    pub fn min(mut self, min: Duration) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Never return a delay longer than `max`.
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Draw a delay from the distribution.
+    #[inline]
+    pub fn sample(&self) -> Duration {
+        self.sample_with(&Rng::default())
+    }
+
+    /// Draw a delay from the distribution using `rng`.
+    pub fn sample_with(&self, rng: &Rng) -> Duration {
+        let secs = match &self.kind {
+            Kind::Uniform { low, high } => low + (high - low) * rng.next_f64(),
+            Kind::Normal { mean, std_dev } => mean + std_dev * standard_normal(rng),
+            Kind::LogNormal { mu, sigma } => (mu + sigma * standard_normal(rng)).exp(),
+            Kind::Exponential { mean } => -mean * (1.0 - rng.next_f64()).ln(),
+            Kind::Empirical(samples) => match samples.len() {
+                0 => 0.0,
+                len => {
+                    let pos = rng.next_f64() * (len - 1) as f64;
+                    let index = pos as usize;
+                    let next = samples[(index + 1).min(len - 1)];
+                    samples[index] + (next - samples[index]) * pos.fract()
+                }
+            },
+        };
+
+        let delay = if secs.is_nan() || secs <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+        };
+        delay.max(self.min).min(self.max)
+    }
+}
+
+/// A standard normal sample, from the Box-Muller transform.
+fn standard_normal(rng: &Rng) -> f64 {
+    let u1 = 1.0 - rng.next_f64();
+    let u2 = rng.next_f64();
+    (-2.0 * u1.ln()).sqrt() * (TAU * u2).cos()
+}
@@ -1,43 +1,264 @@
 use std::{
+    fmt,
     pin::Pin,
     task::{Context, Poll, ready},
+    time::Duration,
 };
 
 use pin_project_lite::pin_project;
-use tokio::time::Sleep;
-use tower::BoxError;
+use tower::{BoxError, Service};
+
+use crate::tower::timer::{Sleep, Time};
 
 pin_project! {
     /// Response future for [`Delay`].
     ///
+    /// The inner service is called right away; its response is only polled
+    /// once the delay has elapsed.
+    ///
     /// [`Delay`]: super::Delay
-    #[derive(Debug)]
-    pub struct ResponseFuture<S> {
+    pub struct ResponseFuture<F> {
         #[pin]
-        response: S,
-        #[pin]
-        sleep: Sleep,
+        response: F,
+        sleep: Option<Sleep>,
     }
 }
 
-impl<S> ResponseFuture<S> {
+impl<F> ResponseFuture<F> {
     // Create a new [`ResponseFuture`]
     #[inline]
-    pub(crate) fn new(response: S, sleep: Sleep) -> Self {
-        ResponseFuture { response, sleep }
+    pub(crate) fn new(response: F, sleep: Sleep) -> Self {
+        ResponseFuture {
+            response,
+            sleep: Some(sleep),
+        }
     }
 }
 
-impl<F, S, E> Future for ResponseFuture<F>
+impl<F, T, E> Future for ResponseFuture<F>
 where
-    F: Future<Output = Result<S, E>>,
+    F: Future<Output = Result<T, E>>,
     E: Into<BoxError>,
 {
-    type Output = Result<S, BoxError>;
+    type Output = Result<T, BoxError>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.project();
-        ready!(this.sleep.poll(cx));
+        if let Some(sleep) = this.sleep.as_mut() {
+            ready!(sleep.as_mut().poll(cx));
+            *this.sleep = None;
+        }
         this.response.poll(cx).map_err(Into::into)
     }
 }
+
+impl<F> fmt::Debug for ResponseFuture<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseFuture")
+            .field("delaying", &self.sleep.is_some())
+            .finish()
+    }
+}
+
+pin_project! {
+    /// Response future that holds the request until a delay has elapsed and
+    /// only then passes it to the inner service.
+    ///
+    /// Used by [`PolicyDelay`] and the rate limit layer.
+    ///
+    /// [`PolicyDelay`]: super::PolicyDelay
+    pub struct DeferredFuture<S, Req>
+    where
+        S: Service<Req>,
+    {
+        #[pin]
+        state: State<S, Req, S::Future>,
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    enum State<S, Req, F> {
+        Delaying {
+            sleep: Sleep,
+            service: S,
+            req: Option<Req>,
+        },
+        Calling {
+            #[pin]
+            response: F,
+        },
+    }
+}
+
+impl<S, Req> DeferredFuture<S, Req>
+where
+    S: Service<Req>,
+{
+    // Create a new [`DeferredFuture`]
+    //
+    // `service` must already be ready. Without a delay the request is dispatched right away.
+    #[inline]
+    pub(crate) fn new(mut service: S, req: Req, delay: Duration, time: &Time) -> Self {
+        let state = if delay.is_zero() {
+            State::Calling {
+                response: service.call(req),
+            }
+        } else {
+            State::Delaying {
+                sleep: time.sleep(delay),
+                service,
+                req: Some(req),
+            }
+        };
+        DeferredFuture { state }
+    }
+}
+
+impl<S, Req> Future for DeferredFuture<S, Req>
+where
+    S: Service<Req>,
+    S::Error: Into<BoxError>,
+{
+    type Output = Result<S::Response, BoxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Delaying {
+                    sleep,
+                    service,
+                    req,
+                } => {
+                    ready!(sleep.as_mut().poll(cx));
+                    ready!(service.poll_ready(cx)).map_err(Into::into)?;
+                    let req = req.take().expect("polled after completion");
+                    let response = service.call(req);
+                    this.state.set(State::Calling { response });
+                }
+                StateProj::Calling { response } => {
+                    return response.poll(cx).map_err(Into::into);
+                }
+            }
+        }
+    }
+}
+
+impl<S, Req> fmt::Debug for DeferredFuture<S, Req>
+where
+    S: Service<Req>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.state {
+            State::Delaying { .. } => "Delaying",
+            State::Calling { .. } => "Calling",
+        };
+        f.debug_struct("DeferredFuture")
+            .field("state", &state)
+            .finish()
+    }
+}
+
+pin_project! {
+    /// Response future for [`PolicyDelay`].
+    ///
+    /// The request is held until the policy has decided on a delay and the
+    /// delay has elapsed, and is only then passed to the inner service.
+    ///
+    /// [`PolicyDelay`]: super::PolicyDelay
+    pub struct PolicyResponseFuture<S, Req, F>
+    where
+        S: Service<Req>,
+    {
+        #[pin]
+        state: PolicyState<S, Req, F>,
+    }
+}
+
+pin_project! {
+    #[project = PolicyStateProj]
+    enum PolicyState<S, Req, F>
+    where
+        S: Service<Req>,
+    {
+        Deciding {
+            #[pin]
+            decision: F,
+            service: Option<S>,
+            req: Option<Req>,
+            time: Time,
+        },
+        Delaying {
+            #[pin]
+            response: DeferredFuture<S, Req>,
+        },
+    }
+}
+
+impl<S, Req, F> PolicyResponseFuture<S, Req, F>
+where
+    S: Service<Req>,
+{
+    // Create a new [`PolicyResponseFuture`]
+    //
+    // `service` must already be ready.
+    #[inline]
+    pub(crate) fn new(service: S, req: Req, decision: F, time: Time) -> Self {
+        PolicyResponseFuture {
+            state: PolicyState::Deciding {
+                decision,
+                service: Some(service),
+                req: Some(req),
+                time,
+            },
+        }
+    }
+}
+
+impl<S, Req, F> Future for PolicyResponseFuture<S, Req, F>
+where
+    S: Service<Req>,
+    S::Error: Into<BoxError>,
+    F: Future<Output = Option<Duration>>,
+{
+    type Output = Result<S::Response, BoxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                PolicyStateProj::Deciding {
+                    decision,
+                    service,
+                    req,
+                    time,
+                } => {
+                    let delay = ready!(decision.poll(cx)).unwrap_or_default();
+                    let service = service.take().expect("polled after completion");
+                    let req = req.take().expect("polled after completion");
+                    let response = DeferredFuture::new(service, req, delay, time);
+                    this.state.set(PolicyState::Delaying { response });
+                }
+                PolicyStateProj::Delaying { response } => {
+                    return response.poll(cx);
+                }
+            }
+        }
+    }
+}
+
+impl<S, Req, F> fmt::Debug for PolicyResponseFuture<S, Req, F>
+where
+    S: Service<Req>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.state {
+            PolicyState::Deciding { .. } => "Deciding",
+            PolicyState::Delaying { .. } => "Delaying",
+        };
+        f.debug_struct("PolicyResponseFuture")
+            .field("state", &state)
+            .finish()
+    }
+}
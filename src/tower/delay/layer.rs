@@ -2,12 +2,25 @@ use std::time::Duration;
 
 use tower::Layer;
 
-use super::service::{Delay, DelayWith, JitterDelay, JitterDelayWith};
+use super::{
+    Distribution,
+    service::{
+        Delay, DelayWith, JitterDelay, JitterDelayWith, PolicyDelay, ThinkTime, ThinkTimeWith,
+    },
+};
+use crate::{
+    rand::Rng,
+    tower::timer::{Time, Timer},
+};
 
-/// A Tower [`Layer`] that introduces a fixed delay before each request.
+/// A Tower [`Layer`] that introduces a fixed delay before each response.
+///
+/// The request is sent right away; use [`PolicyDelayLayer`] to delay the
+/// request itself.
 #[derive(Clone, Debug)]
 pub struct DelayLayer {
     delay: Duration,
+    time: Time,
 }
 
 /// Conditional delay [`Layer`], applies delay based on a predicate.
@@ -17,12 +30,14 @@ pub struct DelayLayer {
 pub struct DelayLayerWith<P> {
     delay: Duration,
     predicate: P,
+    time: Time,
 }
 
-/// A Tower [`Layer`] that introduces a jittered delay before each request.
+/// A Tower [`Layer`] that introduces a jittered delay before each response.
 ///
 /// The actual delay for each request will be randomly chosen within the range
-/// `[base - base*pct, base + base*pct]`.
+/// `[base - base*pct, base + base*pct]`. Like [`DelayLayer`], it holds back
+/// the response, not the request.
 ///
 /// # Example
 ///
@@ -41,6 +56,8 @@ pub struct DelayLayerWith<P> {
 pub struct JitterDelayLayer {
     base: Duration,
     pct: f64,
+    rng: Rng,
+    time: Time,
 }
 
 /// Conditional jitter delay [`Layer`], applies delay based on a predicate.
@@ -51,6 +68,77 @@ pub struct JitterDelayLayerWith<P> {
     base: Duration,
     pct: f64,
     predicate: P,
+    rng: Rng,
+    time: Time,
+}
+
+/// A Tower [`Layer`] that delays each response by a think time drawn from a
+/// [`Distribution`]. The request itself is sent right away.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use wreq::Client;
+/// use wreq_util::tower::delay::{Distribution, ThinkTimeLayer};
+///
+/// // Exponential gaps averaging 3s, between 500ms and 20s
+/// let think_time = Distribution::exponential(Duration::from_secs(3))
+///     .min(Duration::from_millis(500))
+///     .max(Duration::from_secs(20));
+///
+/// let client = Client::builder()
+///     .layer(ThinkTimeLayer::new(think_time))
+///     .build()?;
+/// # Ok::<(), wreq::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThinkTimeLayer {
+    distribution: Distribution,
+    rng: Rng,
+    time: Time,
+}
+
+/// Conditional think time [`Layer`], applies delay based on a predicate.
+///
+/// Created via [`ThinkTimeLayer::when`].
+#[derive(Clone, Debug)]
+pub struct ThinkTimeLayerWith<P> {
+    distribution: Distribution,
+    predicate: P,
+    rng: Rng,
+    time: Time,
+}
+
+/// A Tower [`Layer`] that delays each request by the duration a
+/// [`DelayPolicy`](super::DelayPolicy) decides on.
+///
+/// Unlike `.when()` on the other delay layers, the policy picks the delay per
+/// request, may be asynchronous and stateful, and composes with
+/// [`DelayPolicyExt`](super::policy::DelayPolicyExt).
+///
+/// The request is only passed to the inner service once the delay has
+/// elapsed, which requires the inner service to be `Clone`. The other delay
+/// layers call the inner service right away and hold its response instead.
+///
+/// # Example
+///
+/// ```ignore
+/// use std::time::Duration;
+/// use http::Request;
+/// use wreq_util::tower::delay::PolicyDelayLayer;
+///
+/// let layer = PolicyDelayLayer::new(|req: &Request<_>| match req.uri().path() {
+///     "/checkout" => Some(Duration::from_secs(3)),
+///     "/search" => Some(Duration::from_millis(500)),
+///     _ => None,
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolicyDelayLayer<P> {
+    policy: P,
+    rng: Rng,
+    time: Time,
 }
 
 // ===== impl DelayLayer =====
@@ -59,7 +147,18 @@ impl DelayLayer {
     /// Create a new [`DelayLayer`] with the given delay duration.
     #[inline]
     pub const fn new(delay: Duration) -> Self {
-        DelayLayer { delay }
+        DelayLayer {
+            delay,
+            time: Time::Default,
+        }
+    }
+
+    /// Sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
     }
 
     /// Apply delay only to requests that satisfy a predicate.
@@ -81,7 +180,11 @@ impl DelayLayer {
     where
         P: Fn(&Req) -> bool + Clone,
     {
-        DelayLayerWith::new(self.delay, predicate)
+        DelayLayerWith {
+            delay: self.delay,
+            predicate,
+            time: self.time,
+        }
     }
 }
 
@@ -90,7 +193,7 @@ impl<S> Layer<S> for DelayLayer {
 
     #[inline]
     fn layer(&self, service: S) -> Self::Service {
-        Delay::new(service, self.delay)
+        Delay::new(service, self.delay).with_timer(self.time.clone())
     }
 }
 
@@ -100,7 +203,19 @@ impl<P> DelayLayerWith<P> {
     /// Creates a new [`DelayLayerWith`].
     #[inline]
     pub fn new(delay: Duration, predicate: P) -> Self {
-        Self { delay, predicate }
+        Self {
+            delay,
+            predicate,
+            time: Time::default(),
+        }
+    }
+
+    /// Sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
     }
 }
 
@@ -112,7 +227,7 @@ where
 
     #[inline]
     fn layer(&self, inner: S) -> Self::Service {
-        DelayWith::new(inner, self.delay, self.predicate.clone())
+        DelayWith::new(inner, self.delay, self.predicate.clone()).with_timer(self.time.clone())
     }
 }
 
@@ -139,9 +254,27 @@ impl JitterDelayLayer {
         Self {
             base,
             pct: pct.clamp(0.0, 1.0),
+            rng: Rng::default(),
+            time: Time::default(),
         }
     }
 
+    /// Draw delays from `rng`, e.g. [`Rng::seeded`] for reproducible delays.
+    ///
+    /// The random sequence is shared by all services created by this layer.
+    pub fn rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
+    }
+
     /// Apply jitter delay only to requests that satisfy a predicate.
     ///
     /// Requests that don't match the predicate will pass through without delay.
@@ -161,7 +294,13 @@ impl JitterDelayLayer {
     where
         P: Fn(&Req) -> bool + Clone,
     {
-        JitterDelayLayerWith::new(self.base, self.pct, predicate)
+        JitterDelayLayerWith {
+            base: self.base,
+            pct: self.pct,
+            predicate,
+            rng: self.rng,
+            time: self.time,
+        }
     }
 }
 
@@ -171,6 +310,8 @@ impl<S> Layer<S> for JitterDelayLayer {
     #[inline]
     fn layer(&self, inner: S) -> Self::Service {
         JitterDelay::new(inner, self.base, self.pct)
+            .with_rng(self.rng.clone())
+            .with_timer(self.time.clone())
     }
 }
 
@@ -184,8 +325,26 @@ impl<P> JitterDelayLayerWith<P> {
             base,
             pct: pct.clamp(0.0, 1.0),
             predicate,
+            rng: Rng::default(),
+            time: Time::default(),
         }
     }
+
+    /// Draw delays from `rng`, e.g. [`Rng::seeded`] for reproducible delays.
+    ///
+    /// The random sequence is shared by all services created by this layer.
+    pub fn rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
+    }
 }
 
 impl<P, S> Layer<S> for JitterDelayLayerWith<P>
@@ -197,5 +356,167 @@ where
     #[inline]
     fn layer(&self, inner: S) -> Self::Service {
         JitterDelayWith::new(inner, self.base, self.pct, self.predicate.clone())
+            .with_rng(self.rng.clone())
+            .with_timer(self.time.clone())
+    }
+}
+
+// ===== impl ThinkTimeLayer =====
+
+impl ThinkTimeLayer {
+    /// Create a new [`ThinkTimeLayer`] sampling delays from the given distribution.
+    #[inline]
+    pub fn new(distribution: Distribution) -> Self {
+        Self {
+            distribution,
+            rng: Rng::default(),
+            time: Time::default(),
+        }
+    }
+
+    /// Draw delays from `rng`, e.g. [`Rng::seeded`] for reproducible delays.
+    ///
+    /// The random sequence is shared by all services created by this layer.
+    pub fn rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
+    }
+
+    /// Apply think time only to requests that satisfy a predicate.
+    ///
+    /// Requests that don't match the predicate will pass through without delay.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    /// use http::Request;
+    /// use wreq_util::tower::delay::{Distribution, ThinkTimeLayer};
+    ///
+    /// // Only pause before page navigations, not before subresources
+    /// let layer = ThinkTimeLayer::new(Distribution::log_normal(Duration::from_secs(5), 0.8))
+    ///     .when(|req: &Request<_>| req.headers().get("sec-fetch-mode").is_some_and(|v| v == "navigate"));
+    /// ```
+    pub fn when<P, Req>(self, predicate: P) -> ThinkTimeLayerWith<P>
+    where
+        P: Fn(&Req) -> bool + Clone,
+    {
+        ThinkTimeLayerWith {
+            distribution: self.distribution,
+            predicate,
+            rng: self.rng,
+            time: self.time,
+        }
+    }
+}
+
+impl<S> Layer<S> for ThinkTimeLayer {
+    type Service = ThinkTime<S>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        ThinkTime::new(inner, self.distribution.clone())
+            .with_rng(self.rng.clone())
+            .with_timer(self.time.clone())
+    }
+}
+
+// ===== impl ThinkTimeLayerWith =====
+
+impl<P> ThinkTimeLayerWith<P> {
+    /// Creates a new [`ThinkTimeLayerWith`].
+    #[inline]
+    pub fn new(distribution: Distribution, predicate: P) -> Self {
+        Self {
+            distribution,
+            predicate,
+            rng: Rng::default(),
+            time: Time::default(),
+        }
+    }
+
+    /// Draw delays from `rng`, e.g. [`Rng::seeded`] for reproducible delays.
+    ///
+    /// The random sequence is shared by all services created by this layer.
+    pub fn rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
+    }
+}
+
+impl<P, S> Layer<S> for ThinkTimeLayerWith<P>
+where
+    P: Clone,
+{
+    type Service = ThinkTimeWith<S, P>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        ThinkTimeWith::new(inner, self.distribution.clone(), self.predicate.clone())
+            .with_rng(self.rng.clone())
+            .with_timer(self.time.clone())
+    }
+}
+
+// ===== impl PolicyDelayLayer =====
+
+impl<P> PolicyDelayLayer<P> {
+    /// Create a new [`PolicyDelayLayer`] delaying requests as `policy` decides.
+    ///
+    /// Each service created by the layer gets its own clone of the policy.
+    #[inline]
+    pub fn new(policy: P) -> Self {
+        Self {
+            policy,
+            rng: Rng::default(),
+            time: Time::default(),
+        }
+    }
+
+    /// Draw random delays from `rng`, e.g. [`Rng::seeded`] for reproducible delays.
+    ///
+    /// The random sequence is shared by all services created by this layer and
+    /// passed to the policy, see [`DelayPolicy::delay`](super::DelayPolicy::delay).
+    pub fn rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
+    }
+}
+
+impl<P, S> Layer<S> for PolicyDelayLayer<P>
+where
+    P: Clone,
+{
+    type Service = PolicyDelay<S, P>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        PolicyDelay::new(inner, self.policy.clone())
+            .with_rng(self.rng.clone())
+            .with_timer(self.time.clone())
     }
 }
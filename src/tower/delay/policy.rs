@@ -0,0 +1,566 @@
+//! Delay policies, deciding per request how long to delay it.
+//!
+//! A [`DelayPolicy`] returns `Some(delay)` for requests it applies to and
+//! `None` for requests that should pass through without delay. Policies may be
+//! asynchronous and stateful, and compose with [`and`](DelayPolicyExt::and),
+//! [`or`](DelayPolicyExt::or) and [`not`](DelayPolicyExt::not).
+//!
+//! Combinators short-circuit from left to right: `a.and(b)` only asks `b`
+//! when `a` applies, and `a.or(b)` only when `a` does not. This matters for
+//! stateful policies and for random ones, which draw from the layer's
+//! [`Rng`] only when asked. When `a` decides asynchronously, `b` is asked up
+//! front, but its decision is only awaited when needed.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use http::{Method, Request};
+//! use wreq_util::tower::delay::{
+//!     Distribution, PolicyDelayLayer,
+//!     policy::{DelayPolicyExt, when},
+//! };
+//!
+//! // Longer pauses before checkout than before search
+//! let by_path = |req: &Request<_>| match req.uri().path() {
+//!     path if path.starts_with("/checkout") => Some(Duration::from_secs(3)),
+//!     path if path.starts_with("/search") => Some(Duration::from_millis(500)),
+//!     _ => None,
+//! };
+//!
+//! // Everything else gets a think time, unless it is a `GET`
+//! let think_time = when(|req: &Request<_>| req.method() == Method::GET)
+//!     .not()
+//!     .and(Distribution::log_normal(Duration::from_secs(1), 0.5));
+//!
+//! let layer = PolicyDelayLayer::new(by_path.or(think_time));
+//! ```
+
+use std::{
+    fmt,
+    future::{Ready, ready},
+    pin::Pin,
+    task::{Context, Poll, ready},
+    time::Duration,
+};
+
+use pin_project_lite::pin_project;
+
+use super::{Distribution, jittered_duration};
+use crate::rand::Rng;
+
+/// Decides how long to delay a request.
+///
+/// Implemented for [`Duration`], [`Distribution`], [`Jitter`], the
+/// combinators in this module and any `FnMut(&Req) -> Option<Duration>`
+/// closure. Use [`when`] for boolean predicates and [`async_policy`] for
+/// asynchronous ones.
+pub trait DelayPolicy<Req> {
+    /// The future resolving to the delay.
+    type Future: Future<Output = Option<Duration>>;
+
+    /// Returns the delay for `req`, or `None` to send it right away.
+    ///
+    /// Random delays are drawn from `rng`, the generator of the
+    /// [`PolicyDelayLayer`](super::PolicyDelayLayer). The returned future must
+    /// not borrow `req` or `rng`: anything it needs has to be copied out first.
+    fn delay(&mut self, req: &Req, rng: &Rng) -> Self::Future;
+
+    /// Returns the delay for `req` right away, if the policy decides
+    /// synchronously.
+    ///
+    /// Returns `None` if the decision needs [`delay`](DelayPolicy::delay), which
+    /// must then be called for the same request. Combinators use this to skip
+    /// policies whose decision is not needed without building a future. The
+    /// default implementation always returns `None`.
+    #[inline]
+    fn try_now(&mut self, _req: &Req, _rng: &Rng) -> Option<Option<Duration>> {
+        None
+    }
+}
+
+/// Combinators for [`DelayPolicy`].
+pub trait DelayPolicyExt<Req>: DelayPolicy<Req> + Sized {
+    /// Apply only when both policies apply, delaying by the longer of the two.
+    ///
+    /// `other` is only asked for a delay if `self` applies. If `self` decides
+    /// asynchronously, `other` is asked right away, since the request is gone
+    /// by the time `self` resolves, but its future is only polled if `self`
+    /// applies.
+    fn and<P>(self, other: P) -> And<Self, P>
+    where
+        P: DelayPolicy<Req>,
+    {
+        And {
+            first: self,
+            second: other,
+            decided: None,
+        }
+    }
+
+    /// Apply the first policy that applies.
+    ///
+    /// `other` is only asked for a delay if `self` does not apply. If `self`
+    /// decides asynchronously, `other` is asked right away, since the request
+    /// is gone by the time `self` resolves, but its future is only polled if
+    /// `self` does not apply.
+    fn or<P>(self, other: P) -> Or<Self, P>
+    where
+        P: DelayPolicy<Req>,
+    {
+        Or {
+            first: self,
+            second: other,
+            decided: None,
+        }
+    }
+
+    /// Apply, without delay of its own, exactly when `self` does not.
+    ///
+    /// Combine with [`and`](DelayPolicyExt::and) to set the delay.
+    fn not(self) -> Not<Self> {
+        Not { inner: self }
+    }
+}
+
+impl<P, Req> DelayPolicyExt<Req> for P where P: DelayPolicy<Req> {}
+
+/// A policy applying, without delay of its own, when a predicate holds.
+///
+/// Created by [`when`].
+#[derive(Clone, Copy, Debug)]
+pub struct When<F> {
+    predicate: F,
+}
+
+/// A policy backed by an asynchronous closure.
+///
+/// Created by [`async_policy`].
+#[derive(Clone, Copy)]
+pub struct AsyncPolicy<F> {
+    f: F,
+}
+
+/// A policy delaying every request by a jittered duration.
+///
+/// Created by [`jitter`].
+#[derive(Clone, Copy, Debug)]
+pub struct Jitter {
+    base: Duration,
+    pct: f64,
+}
+
+/// Policy returned by [`DelayPolicyExt::and`].
+#[derive(Clone, Copy, Debug)]
+pub struct And<A, B> {
+    first: A,
+    second: B,
+    // The first decision, taken by `try_now` when the second policy turned
+    // out to be asynchronous.
+    decided: Option<Option<Duration>>,
+}
+
+/// Policy returned by [`DelayPolicyExt::or`].
+#[derive(Clone, Copy, Debug)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+    // The first decision, taken by `try_now` when the second policy turned
+    // out to be asynchronous.
+    decided: Option<Option<Duration>>,
+}
+
+/// Policy returned by [`DelayPolicyExt::not`].
+#[derive(Clone, Copy, Debug)]
+pub struct Not<P> {
+    inner: P,
+}
+
+pin_project! {
+    /// Future for the [`And`] policy.
+    pub struct AndFuture<A, B> {
+        // The first decision, if it was not ready right away.
+        #[pin]
+        first: Option<A>,
+        // The second decision, unless it was skipped.
+        #[pin]
+        second: Option<B>,
+        // The delay of the first policy, once it applied.
+        delay: Option<Duration>,
+    }
+}
+
+pin_project! {
+    /// Future for the [`Or`] policy.
+    pub struct OrFuture<A, B> {
+        // The first decision, if it was not ready right away.
+        #[pin]
+        first: Option<A>,
+        // The second decision, unless it was skipped.
+        #[pin]
+        second: Option<B>,
+        // The delay of the first policy, once it applied.
+        delay: Option<Duration>,
+    }
+}
+
+pin_project! {
+    /// Future for the [`Not`] policy.
+    pub struct NotFuture<F> {
+        #[pin]
+        inner: F,
+    }
+}
+
+/// Create a policy that applies, without delay of its own, when `predicate`
+/// returns `true`.
+///
+/// Combine with [`and`](DelayPolicyExt::and) to set the delay.
+#[inline]
+pub fn when<F, Req>(predicate: F) -> When<F>
+where
+    F: FnMut(&Req) -> bool,
+{
+    When { predicate }
+}
+
+/// Create a policy from an asynchronous closure, e.g. one that consults
+/// shared state.
+///
+/// # Example
+///
+/// ```ignore
+/// use std::{sync::Arc, time::Duration};
+/// use http::Request;
+/// use tokio::sync::Semaphore;
+/// use wreq_util::tower::delay::{PolicyDelayLayer, policy::async_policy};
+///
+/// // Delay requests while another task holds the budget
+/// let budget = Arc::new(Semaphore::new(1));
+/// let layer = PolicyDelayLayer::new(async_policy(move |_: &Request<_>| {
+///     let budget = budget.clone();
+///     async move {
+///         let _permit = budget.acquire().await.ok()?;
+///         Some(Duration::from_millis(100))
+///     }
+/// }));
+/// ```
+#[inline]
+pub fn async_policy<F, Req, Fut>(f: F) -> AsyncPolicy<F>
+where
+    F: FnMut(&Req) -> Fut,
+    Fut: Future<Output = Option<Duration>>,
+{
+    AsyncPolicy { f }
+}
+
+/// Create a policy delaying every request by a random duration within
+/// `[base - base*pct, base + base*pct]`.
+///
+/// `pct` is clamped to `[0.0, 1.0]`. The delay is drawn from the layer's
+/// [`Rng`], see [`PolicyDelayLayer::rng`](super::PolicyDelayLayer::rng).
+#[inline]
+pub fn jitter(base: Duration, pct: f64) -> Jitter {
+    Jitter {
+        base,
+        pct: pct.clamp(0.0, 1.0),
+    }
+}
+
+// ===== impl DelayPolicy =====
+
+impl<Req> DelayPolicy<Req> for Duration {
+    type Future = Ready<Option<Duration>>;
+
+    #[inline]
+    fn delay(&mut self, _: &Req, _: &Rng) -> Self::Future {
+        ready(Some(*self))
+    }
+
+    #[inline]
+    fn try_now(&mut self, _: &Req, _: &Rng) -> Option<Option<Duration>> {
+        Some(Some(*self))
+    }
+}
+
+impl<Req> DelayPolicy<Req> for Distribution {
+    type Future = Ready<Option<Duration>>;
+
+    #[inline]
+    fn delay(&mut self, _: &Req, rng: &Rng) -> Self::Future {
+        ready(Some(self.sample_with(rng)))
+    }
+
+    #[inline]
+    fn try_now(&mut self, _: &Req, rng: &Rng) -> Option<Option<Duration>> {
+        Some(Some(self.sample_with(rng)))
+    }
+}
+
+impl<F, Req> DelayPolicy<Req> for F
+where
+    F: FnMut(&Req) -> Option<Duration>,
+{
+    type Future = Ready<Option<Duration>>;
+
+    #[inline]
+    fn delay(&mut self, req: &Req, _: &Rng) -> Self::Future {
+        ready(self(req))
+    }
+
+    #[inline]
+    fn try_now(&mut self, req: &Req, _: &Rng) -> Option<Option<Duration>> {
+        Some(self(req))
+    }
+}
+
+// ===== impl When =====
+
+impl<F, Req> DelayPolicy<Req> for When<F>
+where
+    F: FnMut(&Req) -> bool,
+{
+    type Future = Ready<Option<Duration>>;
+
+    #[inline]
+    fn delay(&mut self, req: &Req, _: &Rng) -> Self::Future {
+        ready((self.predicate)(req).then_some(Duration::ZERO))
+    }
+
+    #[inline]
+    fn try_now(&mut self, req: &Req, _: &Rng) -> Option<Option<Duration>> {
+        Some((self.predicate)(req).then_some(Duration::ZERO))
+    }
+}
+
+// ===== impl AsyncPolicy =====
+
+impl<F, Req, Fut> DelayPolicy<Req> for AsyncPolicy<F>
+where
+    F: FnMut(&Req) -> Fut,
+    Fut: Future<Output = Option<Duration>>,
+{
+    type Future = Fut;
+
+    #[inline]
+    fn delay(&mut self, req: &Req, _: &Rng) -> Self::Future {
+        (self.f)(req)
+    }
+}
+
+impl<F> fmt::Debug for AsyncPolicy<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncPolicy").finish()
+    }
+}
+
+// ===== impl Jitter =====
+
+impl<Req> DelayPolicy<Req> for Jitter {
+    type Future = Ready<Option<Duration>>;
+
+    #[inline]
+    fn delay(&mut self, _: &Req, rng: &Rng) -> Self::Future {
+        ready(Some(jittered_duration(self.base, self.pct, rng)))
+    }
+
+    #[inline]
+    fn try_now(&mut self, _: &Req, rng: &Rng) -> Option<Option<Duration>> {
+        Some(Some(jittered_duration(self.base, self.pct, rng)))
+    }
+}
+
+// ===== impl And =====
+
+impl<A, B, Req> DelayPolicy<Req> for And<A, B>
+where
+    A: DelayPolicy<Req>,
+    B: DelayPolicy<Req>,
+{
+    type Future = AndFuture<A::Future, B::Future>;
+
+    fn delay(&mut self, req: &Req, rng: &Rng) -> Self::Future {
+        let decided = self.decided.take().or_else(|| self.first.try_now(req, rng));
+        match decided {
+            Some(None) => AndFuture {
+                first: None,
+                second: None,
+                delay: None,
+            },
+            Some(Some(delay)) => AndFuture {
+                first: None,
+                second: Some(self.second.delay(req, rng)),
+                delay: Some(delay),
+            },
+            None => AndFuture {
+                first: Some(self.first.delay(req, rng)),
+                second: Some(self.second.delay(req, rng)),
+                delay: None,
+            },
+        }
+    }
+
+    fn try_now(&mut self, req: &Req, rng: &Rng) -> Option<Option<Duration>> {
+        let Some(first) = self.first.try_now(req, rng)? else {
+            return Some(None);
+        };
+        match self.second.try_now(req, rng) {
+            Some(second) => Some(second.map(|second| first.max(second))),
+            None => {
+                self.decided = Some(Some(first));
+                None
+            }
+        }
+    }
+}
+
+impl<A, B> Future for AndFuture<A, B>
+where
+    A: Future<Output = Option<Duration>>,
+    B: Future<Output = Option<Duration>>,
+{
+    type Output = Option<Duration>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Some(first) = this.first.as_mut().as_pin_mut() {
+            *this.delay = ready!(first.poll(cx));
+            this.first.set(None);
+        }
+        let (Some(first), Some(second)) = (*this.delay, this.second.as_pin_mut()) else {
+            return Poll::Ready(None);
+        };
+        let second = ready!(second.poll(cx));
+        Poll::Ready(second.map(|second| first.max(second)))
+    }
+}
+
+impl<A, B> fmt::Debug for AndFuture<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AndFuture")
+            .field("delay", &self.delay)
+            .finish()
+    }
+}
+
+// ===== impl Or =====
+
+impl<A, B, Req> DelayPolicy<Req> for Or<A, B>
+where
+    A: DelayPolicy<Req>,
+    B: DelayPolicy<Req>,
+{
+    type Future = OrFuture<A::Future, B::Future>;
+
+    fn delay(&mut self, req: &Req, rng: &Rng) -> Self::Future {
+        let decided = self.decided.take().or_else(|| self.first.try_now(req, rng));
+        match decided {
+            Some(Some(delay)) => OrFuture {
+                first: None,
+                second: None,
+                delay: Some(delay),
+            },
+            Some(None) => OrFuture {
+                first: None,
+                second: Some(self.second.delay(req, rng)),
+                delay: None,
+            },
+            None => OrFuture {
+                first: Some(self.first.delay(req, rng)),
+                second: Some(self.second.delay(req, rng)),
+                delay: None,
+            },
+        }
+    }
+
+    fn try_now(&mut self, req: &Req, rng: &Rng) -> Option<Option<Duration>> {
+        if let Some(delay) = self.first.try_now(req, rng)? {
+            return Some(Some(delay));
+        }
+        let second = self.second.try_now(req, rng);
+        if second.is_none() {
+            self.decided = Some(None);
+        }
+        second
+    }
+}
+
+impl<A, B> Future for OrFuture<A, B>
+where
+    A: Future<Output = Option<Duration>>,
+    B: Future<Output = Option<Duration>>,
+{
+    type Output = Option<Duration>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Some(first) = this.first.as_mut().as_pin_mut() {
+            *this.delay = ready!(first.poll(cx));
+            this.first.set(None);
+        }
+        if let Some(delay) = *this.delay {
+            return Poll::Ready(Some(delay));
+        }
+        match this.second.as_pin_mut() {
+            Some(second) => second.poll(cx),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<A, B> fmt::Debug for OrFuture<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrFuture")
+            .field("delay", &self.delay)
+            .finish()
+    }
+}
+
+// ===== impl Not =====
+
+impl<P, Req> DelayPolicy<Req> for Not<P>
+where
+    P: DelayPolicy<Req>,
+{
+    type Future = NotFuture<P::Future>;
+
+    #[inline]
+    fn delay(&mut self, req: &Req, rng: &Rng) -> Self::Future {
+        NotFuture {
+            inner: self.inner.delay(req, rng),
+        }
+    }
+
+    #[inline]
+    fn try_now(&mut self, req: &Req, rng: &Rng) -> Option<Option<Duration>> {
+        self.inner.try_now(req, rng).map(invert)
+    }
+}
+
+impl<F> Future for NotFuture<F>
+where
+    F: Future<Output = Option<Duration>>,
+{
+    type Output = Option<Duration>;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let delay = ready!(self.project().inner.poll(cx));
+        Poll::Ready(invert(delay))
+    }
+}
+
+impl<F> fmt::Debug for NotFuture<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NotFuture").finish()
+    }
+}
+
+/// Turns a decision around: applies, without delay, exactly when `delay` is `None`.
+#[inline]
+fn invert(delay: Option<Duration>) -> Option<Duration> {
+    match delay {
+        Some(_) => None,
+        None => Some(Duration::ZERO),
+    }
+}
@@ -5,13 +5,24 @@ use std::{
 
 use tower::{BoxError, Service};
 
-use super::{future::ResponseFuture, jittered_duration};
+use super::{
+    Distribution,
+    future::{PolicyResponseFuture, ResponseFuture},
+    jittered_duration,
+    policy::DelayPolicy,
+    take_ready,
+};
+use crate::{rand::Rng, tower::timer::Time};
 
-/// A Tower [`Service`] that introduces a fixed delay before each request.
+/// A Tower [`Service`] that introduces a fixed delay before each response.
+///
+/// The inner service is called right away and its response is held until the
+/// delay has elapsed. Use [`PolicyDelay`] to hold the request instead.
 #[derive(Debug, Clone)]
 pub struct Delay<S> {
     inner: S,
     delay: Duration,
+    time: Time,
 }
 
 /// A Tower [`Service`] that conditionally applies fixed delay based on a predicate.
@@ -24,7 +35,7 @@ pub struct DelayWith<S, P> {
     predicate: P,
 }
 
-/// A Tower [`Service`] that applies jittered delay to requests.
+/// A Tower [`Service`] that applies jittered delay to responses.
 ///
 /// This service wraps an inner service and introduces a random delay
 /// (within a configured range) before each request.
@@ -33,6 +44,8 @@ pub struct JitterDelay<S> {
     inner: S,
     base: Duration,
     pct: f64,
+    rng: Rng,
+    time: Time,
 }
 
 /// A Tower [`Service`] that conditionally applies jittered delay based on a predicate.
@@ -45,13 +58,58 @@ pub struct JitterDelayWith<S, P> {
     predicate: P,
 }
 
+/// A Tower [`Service`] that delays each response by a think time drawn from a
+/// [`Distribution`].
+#[derive(Clone, Debug)]
+pub struct ThinkTime<S> {
+    inner: S,
+    distribution: Distribution,
+    rng: Rng,
+    time: Time,
+}
+
+/// A Tower [`Service`] that conditionally applies think time based on a predicate.
+///
+/// Requests that match the predicate are delayed by a sampled think time;
+/// other requests pass through immediately.
+#[derive(Clone, Debug)]
+pub struct ThinkTimeWith<S, P> {
+    inner: ThinkTime<S>,
+    predicate: P,
+}
+
+/// A Tower [`Service`] that delays each request by the duration a
+/// [`DelayPolicy`] decides on.
+///
+/// Unlike the other delay services, the request is held until the delay has
+/// elapsed and only then passed to the inner service, so nothing (e.g.
+/// connection checkout) happens on the wire before then. This needs a
+/// `Clone` inner service.
+#[derive(Clone, Debug)]
+pub struct PolicyDelay<S, P> {
+    inner: S,
+    policy: P,
+    rng: Rng,
+    time: Time,
+}
+
 // ===== impl Delay =====
 
 impl<S> Delay<S> {
     /// Create a new [`Delay`] service wrapping the given inner service
     #[inline]
     pub fn new(inner: S, delay: Duration) -> Self {
-        Delay { inner, delay }
+        Delay {
+            inner,
+            delay,
+            time: Time::default(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_timer(mut self, time: Time) -> Self {
+        self.time = time;
+        self
     }
 }
 
@@ -70,9 +128,8 @@ where
     }
 
     fn call(&mut self, req: Request) -> Self::Future {
-        let response = self.inner.call(req);
-        let sleep = tokio::time::sleep(self.delay);
-        ResponseFuture::new(response, sleep)
+        let sleep = self.time.sleep(self.delay);
+        ResponseFuture::new(self.inner.call(req), sleep)
     }
 }
 
@@ -87,6 +144,12 @@ impl<S, P> DelayWith<S, P> {
             predicate,
         }
     }
+
+    #[inline]
+    pub(crate) fn with_timer(mut self, time: Time) -> Self {
+        self.inner.time = time;
+        self
+    }
 }
 
 impl<S, Req, P> Service<Req> for DelayWith<S, P>
@@ -105,10 +168,14 @@ where
     }
 
     fn call(&mut self, req: Req) -> Self::Future {
-        if !(self.predicate)(&req) {
-            self.inner.delay = Duration::ZERO;
-        }
-        self.inner.call(req)
+        let delay = if (self.predicate)(&req) {
+            self.inner.delay
+        } else {
+            Duration::ZERO
+        };
+
+        let sleep = self.inner.time.sleep(delay);
+        ResponseFuture::new(self.inner.inner.call(req), sleep)
     }
 }
 
@@ -122,8 +189,22 @@ impl<S> JitterDelay<S> {
             inner,
             base,
             pct: pct.clamp(0.0, 1.0),
+            rng: Rng::default(),
+            time: Time::default(),
         }
     }
+
+    #[inline]
+    pub(crate) fn with_rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn with_timer(mut self, time: Time) -> Self {
+        self.time = time;
+        self
+    }
 }
 
 impl<S, Req> Service<Req> for JitterDelay<S>
@@ -141,10 +222,9 @@ where
     }
 
     fn call(&mut self, req: Req) -> Self::Future {
-        let delay = jittered_duration(self.base, self.pct);
-        let sleep = tokio::time::sleep(delay);
-        let fut = self.inner.call(req);
-        ResponseFuture::new(fut, sleep)
+        let delay = jittered_duration(self.base, self.pct, &self.rng);
+        let sleep = self.time.sleep(delay);
+        ResponseFuture::new(self.inner.call(req), sleep)
     }
 }
 
@@ -159,6 +239,18 @@ impl<S, P> JitterDelayWith<S, P> {
             predicate,
         }
     }
+
+    #[inline]
+    pub(crate) fn with_rng(mut self, rng: Rng) -> Self {
+        self.inner.rng = rng;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn with_timer(mut self, time: Time) -> Self {
+        self.inner.time = time;
+        self
+    }
 }
 
 impl<S, Req, P> Service<Req> for JitterDelayWith<S, P>
@@ -178,13 +270,165 @@ where
 
     fn call(&mut self, req: Req) -> Self::Future {
         let delay = if (self.predicate)(&req) {
-            jittered_duration(self.inner.base, self.inner.pct)
+            jittered_duration(self.inner.base, self.inner.pct, &self.inner.rng)
+        } else {
+            Duration::ZERO
+        };
+
+        let sleep = self.inner.time.sleep(delay);
+        ResponseFuture::new(self.inner.inner.call(req), sleep)
+    }
+}
+
+// ===== impl ThinkTime =====
+
+impl<S> ThinkTime<S> {
+    /// Creates a new [`ThinkTime`].
+    #[inline]
+    pub fn new(inner: S, distribution: Distribution) -> Self {
+        Self {
+            inner,
+            distribution,
+            rng: Rng::default(),
+            time: Time::default(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn with_timer(mut self, time: Time) -> Self {
+        self.time = time;
+        self
+    }
+}
+
+impl<S, Req> Service<Req> for ThinkTime<S>
+where
+    S: Service<Req>,
+    S::Error: Into<BoxError>,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = ResponseFuture<S::Future>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        let delay = self.distribution.sample_with(&self.rng);
+        let sleep = self.time.sleep(delay);
+        ResponseFuture::new(self.inner.call(req), sleep)
+    }
+}
+
+// ===== impl ThinkTimeWith =====
+
+impl<S, P> ThinkTimeWith<S, P> {
+    /// Creates a new [`ThinkTimeWith`].
+    #[inline]
+    pub fn new(inner: S, distribution: Distribution, predicate: P) -> Self {
+        Self {
+            inner: ThinkTime::new(inner, distribution),
+            predicate,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_rng(mut self, rng: Rng) -> Self {
+        self.inner.rng = rng;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn with_timer(mut self, time: Time) -> Self {
+        self.inner.time = time;
+        self
+    }
+}
+
+impl<S, Req, P> Service<Req> for ThinkTimeWith<S, P>
+where
+    S: Service<Req>,
+    S::Error: Into<BoxError>,
+    P: Fn(&Req) -> bool,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = ResponseFuture<S::Future>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        let delay = if (self.predicate)(&req) {
+            self.inner.distribution.sample_with(&self.inner.rng)
         } else {
             Duration::ZERO
         };
 
-        let sleep = tokio::time::sleep(delay);
-        let fut = self.inner.inner.call(req);
-        ResponseFuture::new(fut, sleep)
+        let sleep = self.inner.time.sleep(delay);
+        ResponseFuture::new(self.inner.inner.call(req), sleep)
+    }
+}
+
+// ===== impl PolicyDelay =====
+
+impl<S, P> PolicyDelay<S, P> {
+    /// Creates a new [`PolicyDelay`].
+    #[inline]
+    pub fn new(inner: S, policy: P) -> Self {
+        Self {
+            inner,
+            policy,
+            rng: Rng::default(),
+            time: Time::default(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn with_timer(mut self, time: Time) -> Self {
+        self.time = time;
+        self
+    }
+}
+
+impl<S, Req, P> Service<Req> for PolicyDelay<S, P>
+where
+    S: Service<Req> + Clone,
+    S::Error: Into<BoxError>,
+    P: DelayPolicy<Req>,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = PolicyResponseFuture<S, Req, P::Future>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        let decision = self.policy.delay(&req, &self.rng);
+        PolicyResponseFuture::new(
+            take_ready(&mut self.inner),
+            req,
+            decision,
+            self.time.clone(),
+        )
     }
 }
@@ -0,0 +1,102 @@
+use std::time::{Duration, SystemTime};
+
+/// Parse a date as found in `Expires` cookie attributes and HTTP date headers.
+///
+/// Implements the lenient cookie-date algorithm from RFC 6265 §5.1.1, which
+/// accepts the IMF-fixdate, RFC 850 and asctime formats of RFC 9110 as well as
+/// the dashed variants commonly sent by servers.
+pub(crate) fn parse_http_date(src: &str) -> Option<SystemTime> {
+    let mut time = None;
+    let mut day = None;
+    let mut month = None;
+    let mut year = None;
+
+    let tokens = src
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == ':'))
+        .filter(|token| !token.is_empty());
+
+    for token in tokens {
+        if let Some(parsed) = time.is_none().then(|| parse_time(token)).flatten() {
+            time = Some(parsed);
+        } else if let Some(parsed) = day
+            .is_none()
+            .then(|| parse_digits::<u32>(token, 1..=2))
+            .flatten()
+        {
+            day = Some(parsed);
+        } else if let Some(parsed) = month.is_none().then(|| parse_month(token)).flatten() {
+            month = Some(parsed);
+        } else if let Some(parsed) = year
+            .is_none()
+            .then(|| parse_digits::<i64>(token, 2..=4))
+            .flatten()
+        {
+            year = Some(parsed);
+        }
+    }
+
+    let (hour, minute, second) = time?;
+    let (day, month, mut year) = (day?, month?, year?);
+
+    match year {
+        70..=99 => year += 1900,
+        0..=69 => year += 2000,
+        _ => {}
+    }
+
+    if !(1..=31).contains(&day) || year < 1601 || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    let secs = days * 86_400 + i64::from(hour * 3_600 + minute * 60 + second);
+    let secs = u64::try_from(secs).ok()?;
+    SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+}
+
+fn parse_digits<T: std::str::FromStr>(
+    token: &str,
+    len: std::ops::RangeInclusive<usize>,
+) -> Option<T> {
+    if len.contains(&token.len()) && token.bytes().all(|b| b.is_ascii_digit()) {
+        token.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn parse_time(token: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = token.splitn(3, ':');
+    let mut next = || {
+        let part = parts.next()?;
+        if (1..=2).contains(&part.len()) {
+            part.parse().ok()
+        } else {
+            None
+        }
+    };
+    Some((next()?, next()?, next()?))
+}
+
+fn parse_month(token: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+
+    let prefix = token.get(..3)?;
+    MONTHS
+        .iter()
+        .position(|month| prefix.eq_ignore_ascii_case(month))
+        .map(|index| index as u32 + 1)
+}
+
+// from: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
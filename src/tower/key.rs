@@ -0,0 +1,57 @@
+use http::Request;
+
+/// Extracts the key a request is rate or concurrency limited under.
+///
+/// Requests with the same key share one limit. Requests for which no key can
+/// be extracted are not limited.
+///
+/// Implemented for [`ByHost`], [`ByOrigin`], [`Global`] and any
+/// `Fn(&Req) -> Option<String>` closure.
+pub trait KeyExtractor<Req> {
+    /// Returns the key for `req`, or `None` to let the request through unlimited.
+    fn extract(&self, req: &Req) -> Option<String>;
+}
+
+/// Limit each host separately, e.g. `example.com` and `api.example.com` get
+/// their own limits.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByHost;
+
+/// Limit each origin (scheme, host and port) separately.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByOrigin;
+
+/// Share a single limit between all requests.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+impl<B> KeyExtractor<Request<B>> for ByHost {
+    #[inline]
+    fn extract(&self, req: &Request<B>) -> Option<String> {
+        req.uri().host().map(str::to_ascii_lowercase)
+    }
+}
+
+impl<B> KeyExtractor<Request<B>> for ByOrigin {
+    #[inline]
+    fn extract(&self, req: &Request<B>) -> Option<String> {
+        crate::uri::origin(req.uri()).map(|origin| origin.to_ascii_lowercase())
+    }
+}
+
+impl<Req> KeyExtractor<Req> for Global {
+    #[inline]
+    fn extract(&self, _: &Req) -> Option<String> {
+        Some(String::new())
+    }
+}
+
+impl<F, Req> KeyExtractor<Req> for F
+where
+    F: Fn(&Req) -> Option<String>,
+{
+    #[inline]
+    fn extract(&self, req: &Req) -> Option<String> {
+        self(req)
+    }
+}
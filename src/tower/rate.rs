@@ -0,0 +1,60 @@
+//! Per-host rate limiting middleware.
+//!
+//! Spaces out requests so that each host (or origin, or any custom key) sees
+//! at most a configured rate. Requests over the limit wait their turn instead
+//! of failing.
+//!
+//! # Quick Start
+//!
+//! One request per second per host:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use wreq::Client;
+//! use wreq_util::tower::rate::RateLimitLayer;
+//!
+//! let client = Client::builder()
+//!     .layer(RateLimitLayer::token_bucket(1, Duration::from_secs(1)))
+//!     .build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! At most 100 requests per minute per origin, with some jitter:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use wreq::Client;
+//! use wreq_util::tower::rate::{ByOrigin, RateLimitLayer};
+//!
+//! let client = Client::builder()
+//!     .layer(
+//!         RateLimitLayer::sliding_window(100, Duration::from_secs(60))
+//!             .key(ByOrigin)
+//!             .jitter(0.2),
+//!     )
+//!     .build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! # Algorithms
+//!
+//! - [`RateLimitLayer::token_bucket`] refills one token every `per / rate` and holds up to
+//!   [`burst`](RateLimitLayer::burst) tokens, so requests are evenly spaced once the bucket is
+//!   empty.
+//! - [`RateLimitLayer::sliding_window`] allows `max` requests in any window, so a full window of
+//!   requests may go out back to back.
+//!
+//! # Notes
+//!
+//! - Slots are reserved when a request is made, in call order, and the request is handed to the
+//!   inner service once its slot comes up
+//! - Requests without a key (e.g. no host) are not limited
+//! - The limiter state is shared by all clones of a layer, so build the layer once and reuse it
+//!   to share a limit between clients
+
+mod layer;
+mod limiter;
+mod service;
+
+pub use self::{layer::RateLimitLayer, service::RateLimit};
+pub use super::key::{ByHost, ByOrigin, Global, KeyExtractor};
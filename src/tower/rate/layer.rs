@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use tower::Layer;
+
+use super::{
+    ByHost,
+    limiter::{Algorithm, Limiter},
+    service::RateLimit,
+};
+use crate::{
+    rand::Rng,
+    tower::timer::{Time, Timer},
+};
+
+/// A Tower [`Layer`] that spaces out requests sharing a key, by default the
+/// request host.
+///
+/// Requests over the limit are delayed rather than rejected. The limiter state
+/// is shared by all clones of the layer and the services it creates.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use wreq::Client;
+/// use wreq_util::tower::rate::RateLimitLayer;
+///
+/// // 2 requests per second per host, allowing bursts of 5
+/// let client = Client::builder()
+///     .layer(RateLimitLayer::token_bucket(2, Duration::from_secs(1)).burst(5))
+///     .build()?;
+/// # Ok::<(), wreq::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimitLayer<K = ByHost> {
+    limiter: Limiter,
+    jitter: f64,
+    rng: Rng,
+    time: Time,
+    key: K,
+}
+
+// ===== impl RateLimitLayer =====
+
+impl RateLimitLayer {
+    /// Allow `rate` requests every `per` for each host, one at a time.
+    ///
+    /// Use [`burst`](RateLimitLayer::burst) to let idle hosts take several
+    /// requests at once. A `rate` of zero is treated as one.
+    pub fn token_bucket(rate: u32, per: Duration) -> Self {
+        Self::with_algorithm(Algorithm::TokenBucket {
+            interval: per / rate.max(1),
+            burst: 1,
+        })
+    }
+
+    /// Allow at most `max` requests in any `window` for each host.
+    ///
+    /// A `max` of zero is treated as one.
+    pub fn sliding_window(max: u32, window: Duration) -> Self {
+        Self::with_algorithm(Algorithm::SlidingWindow {
+            max: max.max(1),
+            window,
+        })
+    }
+
+    fn with_algorithm(algorithm: Algorithm) -> Self {
+        RateLimitLayer {
+            limiter: Limiter::new(algorithm),
+            jitter: 0.0,
+            rng: Rng::default(),
+            time: Time::default(),
+            key: ByHost,
+        }
+    }
+}
+
+impl<K> RateLimitLayer<K> {
+    /// Set the number of requests a token bucket can take at once after being
+    /// idle. Has no effect on sliding windows.
+    ///
+    /// This resets the limiter state shared by existing clones of the layer.
+    pub fn burst(mut self, burst: u32) -> Self {
+        if let Algorithm::TokenBucket { interval, .. } = *self.limiter.algorithm() {
+            self.limiter = Limiter::new(Algorithm::TokenBucket {
+                interval,
+                burst: burst.max(1),
+            });
+        }
+        self
+    }
+
+    /// Add a random extra delay of up to `pct` of the request interval to
+    /// requests that are held back, so they don't line up on an exact grid.
+    ///
+    /// `pct` is clamped to `[0.0, 1.0]`.
+    pub fn jitter(mut self, pct: f64) -> Self {
+        self.jitter = pct.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Draw jitter from `rng`, e.g. [`Rng::seeded`] for reproducible delays.
+    pub fn rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Measure and sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.time = Time::new(timer);
+        self
+    }
+
+    /// Choose what requests are grouped by, e.g. [`ByOrigin`](super::ByOrigin)
+    /// or a closure.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    /// use http::Request;
+    /// use wreq_util::tower::rate::RateLimitLayer;
+    ///
+    /// // One request per second per API token
+    /// let layer = RateLimitLayer::token_bucket(1, Duration::from_secs(1)).key(|req: &Request<_>| {
+    ///     req.headers()
+    ///         .get("x-api-token")
+    ///         .and_then(|value| value.to_str().ok())
+    ///         .map(str::to_owned)
+    /// });
+    /// ```
+    pub fn key<K2>(self, key: K2) -> RateLimitLayer<K2> {
+        RateLimitLayer {
+            limiter: self.limiter,
+            jitter: self.jitter,
+            rng: self.rng,
+            time: self.time,
+            key,
+        }
+    }
+}
+
+impl<S, K> Layer<S> for RateLimitLayer<K>
+where
+    K: Clone,
+{
+    type Service = RateLimit<S, K>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit::new(
+            inner,
+            self.limiter.clone(),
+            self.jitter,
+            self.rng.clone(),
+            self.time.clone(),
+            self.key.clone(),
+        )
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Stale keys are swept after this many reservations.
+const SWEEP_INTERVAL: u32 = 1024;
+
+/// The algorithm used to space requests sharing a key.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Algorithm {
+    /// A token bucket refilled every `interval`, holding up to `burst` tokens.
+    ///
+    /// Implemented as the equivalent generic cell rate algorithm, which only
+    /// needs to remember the theoretical arrival time of the next request.
+    TokenBucket { interval: Duration, burst: u32 },
+
+    /// At most `max` requests in any `window`.
+    SlidingWindow { max: u32, window: Duration },
+}
+
+/// Per-key limiter state, shared between clones of a layer and its services.
+#[derive(Clone, Debug)]
+pub(crate) struct Limiter {
+    algorithm: Algorithm,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    slots: HashMap<String, Slot>,
+    reservations: u32,
+}
+
+#[derive(Debug)]
+enum Slot {
+    TokenBucket { tat: Instant },
+    SlidingWindow { times: VecDeque<Instant> },
+}
+
+// ===== impl Algorithm =====
+
+impl Algorithm {
+    /// The average spacing between requests once the limit is reached.
+    pub(crate) fn interval(&self) -> Duration {
+        match *self {
+            Algorithm::TokenBucket { interval, .. } => interval,
+            Algorithm::SlidingWindow { max, window } => window / max.max(1),
+        }
+    }
+
+    fn slot(&self, now: Instant) -> Slot {
+        match self {
+            Algorithm::TokenBucket { .. } => Slot::TokenBucket { tat: now },
+            Algorithm::SlidingWindow { max, .. } => Slot::SlidingWindow {
+                times: VecDeque::with_capacity(*max as usize),
+            },
+        }
+    }
+
+    /// Reserve the next free slot and return how long to wait for it.
+    fn reserve(&self, slot: &mut Slot, now: Instant) -> Duration {
+        match (*self, slot) {
+            (Algorithm::TokenBucket { interval, burst }, Slot::TokenBucket { tat }) => {
+                let tolerance = interval * burst.saturating_sub(1);
+                let next = (*tat).max(now);
+                let allowed = next.checked_sub(tolerance).unwrap_or(now);
+                *tat = next + interval;
+                allowed.saturating_duration_since(now)
+            }
+            (Algorithm::SlidingWindow { max, window }, Slot::SlidingWindow { times }) => {
+                let max = max.max(1) as usize;
+                let at = if times.len() < max {
+                    now
+                } else {
+                    (times[times.len() - max] + window).max(now)
+                };
+                times.push_back(at);
+                while times.len() > max {
+                    times.pop_front();
+                }
+                at.saturating_duration_since(now)
+            }
+            // A slot is always created by the algorithm that uses it.
+            _ => Duration::ZERO,
+        }
+    }
+
+    fn is_stale(&self, slot: &Slot, now: Instant) -> bool {
+        match (*self, slot) {
+            (Algorithm::TokenBucket { .. }, Slot::TokenBucket { tat }) => *tat <= now,
+            (Algorithm::SlidingWindow { window, .. }, Slot::SlidingWindow { times }) => {
+                times.back().is_none_or(|last| *last + window <= now)
+            }
+            _ => true,
+        }
+    }
+}
+
+// ===== impl Limiter =====
+
+impl Limiter {
+    pub(crate) fn new(algorithm: Algorithm) -> Self {
+        Limiter {
+            algorithm,
+            state: Arc::default(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn algorithm(&self) -> &Algorithm {
+        &self.algorithm
+    }
+
+    /// Reserve a slot for `key` and return how long the request must wait.
+    pub(crate) fn reserve(&self, key: String, now: Instant) -> Duration {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        state.reservations += 1;
+        if state.reservations >= SWEEP_INTERVAL {
+            state.reservations = 0;
+            state
+                .slots
+                .retain(|_, slot| !self.algorithm.is_stale(slot, now));
+        }
+
+        let slot = state
+            .slots
+            .entry(key)
+            .or_insert_with(|| self.algorithm.slot(now));
+        self.algorithm.reserve(slot, now)
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use tower::{BoxError, Service};
+
+use super::{KeyExtractor, limiter::Limiter};
+use crate::{
+    rand::Rng,
+    tower::{
+        delay::{DeferredFuture, jittered_duration, take_ready},
+        timer::Time,
+    },
+};
+
+/// A Tower [`Service`] that delays requests to stay within a per-key rate limit.
+///
+/// Created by [`RateLimitLayer`](super::RateLimitLayer).
+#[derive(Clone, Debug)]
+pub struct RateLimit<S, K> {
+    inner: S,
+    limiter: Limiter,
+    jitter: f64,
+    rng: Rng,
+    time: Time,
+    key: K,
+}
+
+// ===== impl RateLimit =====
+
+impl<S, K> RateLimit<S, K> {
+    #[inline]
+    pub(crate) fn new(
+        inner: S,
+        limiter: Limiter,
+        jitter: f64,
+        rng: Rng,
+        time: Time,
+        key: K,
+    ) -> Self {
+        RateLimit {
+            inner,
+            limiter,
+            jitter,
+            rng,
+            time,
+            key,
+        }
+    }
+}
+
+impl<S, K, Request> Service<Request> for RateLimit<S, K>
+where
+    S: Service<Request> + Clone,
+    S::Error: Into<BoxError>,
+    K: KeyExtractor<Request>,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = DeferredFuture<S, Request>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let mut delay = match self.key.extract(&req) {
+            Some(key) => self.limiter.reserve(key, self.time.now()),
+            None => Duration::ZERO,
+        };
+
+        // Only held back requests are jittered; the extra delay is not reserved,
+        // so it never pushes back later requests.
+        if !delay.is_zero() && self.jitter > 0.0 {
+            let max = self.limiter.algorithm().interval().mul_f64(self.jitter);
+            delay += jittered_duration(max / 2, 1.0, &self.rng);
+        }
+
+        DeferredFuture::new(take_ready(&mut self.inner), req, delay, &self.time)
+    }
+}
@@ -0,0 +1,60 @@
+//! Browser-like redirect following.
+//!
+//! Follows `301`, `302`, `303`, `307` and `308` responses with the rules of the
+//! Fetch standard that browsers implement, so that every hop of a redirect
+//! chain looks like the request a browser would send.
+//!
+//! # Quick Start
+//!
+//! ```no_run
+//! use wreq::Client;
+//! use wreq_util::{Emulation, tower::redirect::BrowserRedirectLayer};
+//!
+//! let client = Client::builder()
+//!     .emulation(Emulation::Chrome147)
+//!     .layer(BrowserRedirectLayer::new())
+//!     .build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! # Rules
+//!
+//! - `POST` becomes `GET` on `301` and `302`, and any method other than `GET` or `HEAD` becomes
+//!   `GET` on `303`. The body and the body headers (`Content-Type`, `Content-Length`, ...) and
+//!   `Origin` are dropped. `307` and `308` keep the method and body
+//! - `Authorization`, `Proxy-Authorization` and `Cookie` set on the request are removed when the
+//!   redirect leaves the origin, and a kept `Origin` becomes `null`
+//! - `Referer` is recomputed for each hop from the original referrer using
+//!   `strict-origin-when-cross-origin`, or the policy set by a redirect's `Referrer-Policy`
+//! - `sec-fetch-site` is raised to `same-site` or `cross-site` as the chain leaves the initiator.
+//!   User-initiated navigations keep `none`
+//! - At most 20 redirects are followed, the limit used by both Chrome and Firefox. Longer chains
+//!   fail with [`TooManyRedirects`]
+//!
+//! Bodies of a known size, such as bytes or strings, are buffered before the
+//! request is sent so `307` and `308` can send them again. Streamed bodies of
+//! unknown length cannot be replayed; for those the redirect response is
+//! returned instead.
+//!
+//! # Notes
+//!
+//! - Keep the `wreq` redirect policy at its default of `Policy::none()`
+//! - Emulation headers are applied below this layer and are re-applied on every hop
+//! - Add this layer after a [`CookieManagerLayer`] so that it wraps the cookie layer and cookies
+//!   set by intermediate redirects are stored and sent
+//!
+//! [`CookieManagerLayer`]: https://docs.rs/wreq-util/latest/wreq_util/tower/cookie/struct.CookieManagerLayer.html
+
+mod error;
+mod future;
+mod layer;
+mod policy;
+mod service;
+
+pub use self::{
+    error::TooManyRedirects, future::ResponseFuture, layer::BrowserRedirectLayer,
+    service::BrowserRedirect,
+};
+
+/// Redirect limit of Chrome (`kMaxRedirects`) and Firefox (`network.http.redirection-limit`).
+const DEFAULT_MAX_REDIRECTS: usize = 20;
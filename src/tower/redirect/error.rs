@@ -0,0 +1,31 @@
+use std::{error::Error, fmt};
+
+/// Error returned when a redirect chain exceeds the configured number of hops.
+///
+/// Browsers report this as a failed navigation, e.g. `ERR_TOO_MANY_REDIRECTS`
+/// in Chrome, rather than handing the last redirect response to the page.
+#[derive(Debug)]
+pub struct TooManyRedirects {
+    max_redirects: usize,
+}
+
+impl TooManyRedirects {
+    #[inline]
+    pub(crate) fn new(max_redirects: usize) -> Self {
+        TooManyRedirects { max_redirects }
+    }
+
+    /// Returns the number of redirects that were followed before giving up.
+    #[inline]
+    pub fn max_redirects(&self) -> usize {
+        self.max_redirects
+    }
+}
+
+impl fmt::Display for TooManyRedirects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "too many redirects (limit: {})", self.max_redirects)
+    }
+}
+
+impl Error for TooManyRedirects {}
@@ -0,0 +1,194 @@
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use http::{Request, Response, StatusCode, header::LOCATION, request::Parts};
+use http_body::Body;
+use pin_project_lite::pin_project;
+use tower::{BoxError, Service};
+
+use super::{
+    TooManyRedirects,
+    policy::{Chain, FetchSite, rewrites_to_get},
+};
+use crate::{
+    tower::replay::{self, Buffer},
+    uri,
+};
+
+pin_project! {
+    /// Response future for [`BrowserRedirect`].
+    ///
+    /// [`BrowserRedirect`]: super::BrowserRedirect
+    pub struct ResponseFuture<S, B>
+    where
+        S: Service<Request<B>>,
+        B: Body,
+    {
+        #[pin]
+        state: State<S::Future, B>,
+        service: S,
+        parts: Parts,
+        // The buffered body, sent again on every hop that keeps it.
+        body: Option<B::Data>,
+        replayable: bool,
+        max_redirects: usize,
+        chain: Chain,
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    enum State<F, B> {
+        Buffering {
+            #[pin]
+            buffer: Buffer<B>,
+        },
+        Pending {
+            #[pin]
+            future: F,
+        },
+        Redirect,
+    }
+}
+
+impl<S, B> ResponseFuture<S, B>
+where
+    S: Service<Request<B>>,
+    B: Body,
+{
+    // Create a new [`ResponseFuture`]
+    //
+    // A body of known size is buffered before the first request is sent, so
+    // 307 and 308 redirects can send it again. Other bodies go out right away.
+    pub(crate) fn new(
+        mut service: S,
+        req: Request<B>,
+        max_redirects: usize,
+        site: Option<FetchSite>,
+    ) -> Self {
+        let (parts, body) = req.into_parts();
+        let chain = Chain::new(parts.uri.clone(), &parts.headers, site);
+        let (state, replayable) = match body.size_hint().exact() {
+            Some(len) if len > 0 => (
+                State::Buffering {
+                    buffer: Buffer::new(body),
+                },
+                true,
+            ),
+            exact => (
+                State::Pending {
+                    future: service.call(Request::from_parts(parts.clone(), body)),
+                },
+                exact == Some(0),
+            ),
+        };
+        ResponseFuture {
+            state,
+            service,
+            parts,
+            body: None,
+            replayable,
+            max_redirects,
+            chain,
+        }
+    }
+}
+
+impl<S, B, ResBody> Future for ResponseFuture<S, B>
+where
+    S: Service<Request<B>, Response = Response<ResBody>>,
+    S::Error: Into<BoxError>,
+    B: Body + Default + From<B::Data>,
+    B::Data: AsRef<[u8]> + From<Vec<u8>> + Clone,
+    B::Error: Into<BoxError>,
+{
+    type Output = Result<Response<ResBody>, BoxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Buffering { buffer } => {
+                    let data = ready!(buffer.poll(cx))?;
+                    let request = Request::from_parts(this.parts.clone(), B::from(data.clone()));
+                    *this.body = Some(data);
+                    let future = this.service.call(request);
+                    this.state.set(State::Pending { future });
+                }
+                StateProj::Pending { future } => {
+                    let response = ready!(future.poll(cx)).map_err(Into::into)?;
+
+                    if !matches!(
+                        response.status(),
+                        StatusCode::MOVED_PERMANENTLY
+                            | StatusCode::FOUND
+                            | StatusCode::SEE_OTHER
+                            | StatusCode::TEMPORARY_REDIRECT
+                            | StatusCode::PERMANENT_REDIRECT
+                    ) {
+                        return Poll::Ready(Ok(response));
+                    }
+
+                    let target = response
+                        .headers()
+                        .get(LOCATION)
+                        .and_then(|location| location.to_str().ok())
+                        .and_then(|location| uri::resolve(&this.parts.uri, location))
+                        .filter(|target| matches!(target.scheme_str(), Some("http" | "https")));
+                    let Some(target) = target else {
+                        return Poll::Ready(Ok(response));
+                    };
+
+                    // A body of unknown size was streamed out and cannot be sent
+                    // again, like a stream upload that cannot be replayed.
+                    if !*this.replayable && !rewrites_to_get(response.status(), &this.parts.method)
+                    {
+                        return Poll::Ready(Ok(response));
+                    }
+
+                    if this.chain.hops >= *this.max_redirects {
+                        return Poll::Ready(Err(TooManyRedirects::new(*this.max_redirects).into()));
+                    }
+
+                    let parts = &mut *this.parts;
+                    if this.chain.follow(
+                        response.status(),
+                        response.headers(),
+                        &mut parts.method,
+                        &parts.uri,
+                        &target,
+                        &mut parts.headers,
+                    ) {
+                        *this.body = None;
+                        *this.replayable = true;
+                    }
+                    parts.uri = target;
+                    this.state.set(State::Redirect);
+                }
+                StateProj::Redirect => {
+                    ready!(this.service.poll_ready(cx)).map_err(Into::into)?;
+                    let body = replay::replay(this.body.as_ref());
+                    let request = Request::from_parts(this.parts.clone(), body);
+                    let future = this.service.call(request);
+                    this.state.set(State::Pending { future });
+                }
+            }
+        }
+    }
+}
+
+impl<S, B> fmt::Debug for ResponseFuture<S, B>
+where
+    S: Service<Request<B>>,
+    B: Body,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseFuture")
+            .field("uri", &self.parts.uri)
+            .field("hops", &self.chain.hops)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,66 @@
+use tower::Layer;
+
+use super::{DEFAULT_MAX_REDIRECTS, policy::FetchSite, service::BrowserRedirect};
+
+/// A Tower [`Layer`] that follows redirects the way a browser does.
+///
+/// See the [module docs](super) for the rules applied on each hop.
+#[derive(Clone, Debug)]
+pub struct BrowserRedirectLayer {
+    max_redirects: usize,
+    site: Option<FetchSite>,
+}
+
+// ===== impl BrowserRedirectLayer =====
+
+impl BrowserRedirectLayer {
+    /// Create a new [`BrowserRedirectLayer`] that follows up to 20 redirects.
+    #[inline]
+    pub const fn new() -> Self {
+        BrowserRedirectLayer {
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            site: None,
+        }
+    }
+
+    /// Set the maximum number of redirects to follow before failing.
+    #[inline]
+    pub const fn max_redirects(mut self, max_redirects: usize) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+
+    /// Track `sec-fetch-site` for requests sent with the given [`RequestKind`].
+    ///
+    /// Emulation headers are applied below this layer, so the initial
+    /// `sec-fetch-site` of a request is not visible here unless it was set on
+    /// the request itself. Set this to the request kind used by the emulation
+    /// so that later hops carry the right value. The default matches
+    /// [`RequestKind::Navigate`].
+    ///
+    /// [`RequestKind`]: crate::RequestKind
+    /// [`RequestKind::Navigate`]: crate::RequestKind::Navigate
+    #[cfg(feature = "emulation")]
+    #[inline]
+    pub fn request_kind(mut self, request_kind: crate::RequestKind) -> Self {
+        let site = http::HeaderValue::from_static(request_kind.sec_fetch_site());
+        self.site = FetchSite::from_header(&site);
+        self
+    }
+}
+
+impl Default for BrowserRedirectLayer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for BrowserRedirectLayer {
+    type Service = BrowserRedirect<S>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        BrowserRedirect::new(inner, self.max_redirects, self.site)
+    }
+}
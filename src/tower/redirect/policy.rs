@@ -0,0 +1,238 @@
+use http::{
+    HeaderMap, HeaderValue, Method, StatusCode, Uri,
+    header::{
+        AUTHORIZATION, CONTENT_ENCODING, CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_LOCATION,
+        CONTENT_TYPE, COOKIE, HOST, ORIGIN, PROXY_AUTHORIZATION, REFERER, REFERRER_POLICY,
+        TRANSFER_ENCODING,
+    },
+};
+
+use crate::uri;
+
+const SEC_FETCH_SITE: &str = "sec-fetch-site";
+
+/// The `sec-fetch-site` value of a request chain.
+///
+/// Variants are ordered from the most to the least trusted, so the value of a
+/// chain is the maximum over all of its hops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum FetchSite {
+    /// A user-initiated navigation, which keeps `none` for the whole chain.
+    None,
+    SameOrigin,
+    SameSite,
+    CrossSite,
+}
+
+/// A referrer policy as defined by the W3C Referrer Policy specification.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum ReferrerPolicy {
+    NoReferrer,
+    NoReferrerWhenDowngrade,
+    SameOrigin,
+    Origin,
+    StrictOrigin,
+    OriginWhenCrossOrigin,
+    #[default]
+    StrictOriginWhenCrossOrigin,
+    UnsafeUrl,
+}
+
+// ===== impl FetchSite =====
+
+impl FetchSite {
+    pub(crate) fn from_header(value: &HeaderValue) -> Option<Self> {
+        match value.as_bytes() {
+            b"none" => Some(FetchSite::None),
+            b"same-origin" => Some(FetchSite::SameOrigin),
+            b"same-site" => Some(FetchSite::SameSite),
+            b"cross-site" => Some(FetchSite::CrossSite),
+            _ => None,
+        }
+    }
+
+    /// The value after following a redirect from `initiator`'s chain to `target`.
+    fn next(self, initiator: &Uri, target: &Uri) -> Self {
+        let relation = if uri::same_origin(initiator, target) {
+            FetchSite::SameOrigin
+        } else if uri::same_site(initiator, target) {
+            FetchSite::SameSite
+        } else {
+            FetchSite::CrossSite
+        };
+
+        match self {
+            FetchSite::None => FetchSite::None,
+            site => site.max(relation),
+        }
+    }
+
+    fn as_header(self) -> HeaderValue {
+        HeaderValue::from_static(match self {
+            FetchSite::None => "none",
+            FetchSite::SameOrigin => "same-origin",
+            FetchSite::SameSite => "same-site",
+            FetchSite::CrossSite => "cross-site",
+        })
+    }
+}
+
+// ===== impl ReferrerPolicy =====
+
+impl ReferrerPolicy {
+    /// Parse a `Referrer-Policy` header, where the last recognized token wins.
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get_all(REFERRER_POLICY)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(|token| match token.trim().to_ascii_lowercase().as_str() {
+                "no-referrer" => Some(ReferrerPolicy::NoReferrer),
+                "no-referrer-when-downgrade" => Some(ReferrerPolicy::NoReferrerWhenDowngrade),
+                "same-origin" => Some(ReferrerPolicy::SameOrigin),
+                "origin" => Some(ReferrerPolicy::Origin),
+                "strict-origin" => Some(ReferrerPolicy::StrictOrigin),
+                "origin-when-cross-origin" => Some(ReferrerPolicy::OriginWhenCrossOrigin),
+                "strict-origin-when-cross-origin" => {
+                    Some(ReferrerPolicy::StrictOriginWhenCrossOrigin)
+                }
+                "unsafe-url" => Some(ReferrerPolicy::UnsafeUrl),
+                _ => None,
+            })
+            .next_back()
+    }
+
+    /// Compute the `Referer` to send to `target` for the given referrer URL.
+    fn referer(self, referrer: &Uri, target: &Uri) -> Option<HeaderValue> {
+        let full = || HeaderValue::try_from(referrer.to_string()).ok();
+        let origin =
+            || uri::origin(referrer).and_then(|origin| HeaderValue::try_from(origin + "/").ok());
+        let same_origin = uri::same_origin(referrer, target);
+        let downgrade =
+            referrer.scheme_str() == Some("https") && target.scheme_str() != Some("https");
+
+        match self {
+            ReferrerPolicy::NoReferrer => None,
+            ReferrerPolicy::UnsafeUrl => full(),
+            ReferrerPolicy::Origin => origin(),
+            ReferrerPolicy::SameOrigin => same_origin.then(full).flatten(),
+            ReferrerPolicy::NoReferrerWhenDowngrade => (!downgrade).then(full).flatten(),
+            ReferrerPolicy::StrictOrigin => (!downgrade).then(origin).flatten(),
+            ReferrerPolicy::OriginWhenCrossOrigin if same_origin => full(),
+            ReferrerPolicy::OriginWhenCrossOrigin => origin(),
+            ReferrerPolicy::StrictOriginWhenCrossOrigin if same_origin => full(),
+            ReferrerPolicy::StrictOriginWhenCrossOrigin => (!downgrade).then(origin).flatten(),
+        }
+    }
+}
+
+/// Whether following `status` turns the request into a body-less `GET`, per the
+/// HTTP-redirect fetch steps of the Fetch standard.
+pub(crate) fn rewrites_to_get(status: StatusCode, method: &Method) -> bool {
+    match status {
+        StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND => *method == Method::POST,
+        StatusCode::SEE_OTHER => *method != Method::GET && *method != Method::HEAD,
+        _ => false,
+    }
+}
+
+/// State carried across the hops of one redirect chain.
+#[derive(Debug)]
+pub(crate) struct Chain {
+    /// The URL of the first request, which stands in for the request initiator.
+    initiator: Uri,
+    /// The referrer URL of the first request, if any.
+    referrer: Option<Uri>,
+    referrer_policy: ReferrerPolicy,
+    site: Option<FetchSite>,
+    pub(crate) hops: usize,
+}
+
+// ===== impl Chain =====
+
+impl Chain {
+    pub(crate) fn new(initiator: Uri, headers: &HeaderMap, site: Option<FetchSite>) -> Self {
+        let referrer = headers
+            .get(REFERER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<Uri>().ok());
+        let site = headers
+            .get(SEC_FETCH_SITE)
+            .and_then(FetchSite::from_header)
+            .or(site);
+
+        Chain {
+            initiator,
+            referrer,
+            referrer_policy: ReferrerPolicy::default(),
+            site,
+            hops: 0,
+        }
+    }
+
+    /// Rewrite the request parts for the next hop of the chain.
+    ///
+    /// Returns `true` when the request body must be dropped.
+    pub(crate) fn follow(
+        &mut self,
+        status: StatusCode,
+        response_headers: &HeaderMap,
+        method: &mut Method,
+        current: &Uri,
+        target: &Uri,
+        headers: &mut HeaderMap,
+    ) -> bool {
+        self.hops += 1;
+
+        let drop_body = rewrites_to_get(status, method);
+        if drop_body {
+            *method = Method::GET;
+            for name in [
+                CONTENT_ENCODING,
+                CONTENT_LANGUAGE,
+                CONTENT_LOCATION,
+                CONTENT_TYPE,
+                CONTENT_LENGTH,
+                TRANSFER_ENCODING,
+                ORIGIN,
+            ] {
+                headers.remove(name);
+            }
+        }
+
+        // Credentials set on the request belong to the origin they were sent to.
+        let cross_origin = !uri::same_origin(current, target);
+        if cross_origin {
+            headers.remove(AUTHORIZATION);
+            headers.remove(PROXY_AUTHORIZATION);
+            headers.remove(COOKIE);
+
+            // A cross-origin redirect taints the origin of a request that keeps its body.
+            if headers.contains_key(ORIGIN) {
+                headers.insert(ORIGIN, HeaderValue::from_static("null"));
+            }
+        }
+        headers.remove(HOST);
+
+        if let Some(policy) = ReferrerPolicy::from_headers(response_headers) {
+            self.referrer_policy = policy;
+        }
+        match self
+            .referrer
+            .as_ref()
+            .and_then(|referrer| self.referrer_policy.referer(referrer, target))
+        {
+            Some(referer) => headers.insert(REFERER, referer),
+            None => headers.remove(REFERER),
+        };
+
+        if let Some(site) = self.site {
+            let site = site.next(&self.initiator, target);
+            self.site = Some(site);
+            headers.insert(SEC_FETCH_SITE, site.as_header());
+        }
+
+        drop_body
+    }
+}
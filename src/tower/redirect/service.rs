@@ -0,0 +1,53 @@
+use std::task::{Context, Poll};
+
+use http::{Request, Response};
+use http_body::Body;
+use tower::{BoxError, Service};
+
+use super::{future::ResponseFuture, policy::FetchSite};
+
+/// A Tower [`Service`] that follows redirects the way a browser does.
+#[derive(Clone, Debug)]
+pub struct BrowserRedirect<S> {
+    inner: S,
+    max_redirects: usize,
+    site: Option<FetchSite>,
+}
+
+// ===== impl BrowserRedirect =====
+
+impl<S> BrowserRedirect<S> {
+    #[inline]
+    pub(crate) fn new(inner: S, max_redirects: usize, site: Option<FetchSite>) -> Self {
+        BrowserRedirect {
+            inner,
+            max_redirects,
+            site,
+        }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for BrowserRedirect<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone,
+    S::Error: Into<BoxError>,
+    ReqBody: Body + Default + From<ReqBody::Data>,
+    ReqBody::Data: AsRef<[u8]> + From<Vec<u8>> + Clone,
+    ReqBody::Error: Into<BoxError>,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = ResponseFuture<S, ReqBody>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        // Use the service that was driven to readiness, and keep a clone for later hops.
+        let clone = self.inner.clone();
+        let service = std::mem::replace(&mut self.inner, clone);
+        ResponseFuture::new(service, req, self.max_redirects, self.site)
+    }
+}
@@ -0,0 +1,65 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use http_body::Body;
+use pin_project_lite::pin_project;
+use tower::BoxError;
+
+pin_project! {
+    /// Collects a request body into a single chunk so it can be sent again.
+    ///
+    /// Only used for bodies of a known size; a stream of unknown length may be
+    /// arbitrarily large and is never buffered.
+    pub(crate) struct Buffer<B> {
+        #[pin]
+        body: B,
+        buf: Vec<u8>,
+    }
+}
+
+impl<B> Buffer<B> {
+    #[inline]
+    pub(crate) fn new(body: B) -> Self {
+        Buffer {
+            body,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl<B> Future for Buffer<B>
+where
+    B: Body,
+    B::Data: AsRef<[u8]> + From<Vec<u8>>,
+    B::Error: Into<BoxError>,
+{
+    type Output = Result<B::Data, BoxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.body.as_mut().poll_frame(cx)) {
+                Some(Ok(frame)) => {
+                    // Trailers are not replayed.
+                    if let Ok(data) = frame.into_data() {
+                        this.buf.extend_from_slice(data.as_ref());
+                    }
+                }
+                Some(Err(err)) => return Poll::Ready(Err(err.into())),
+                None => return Poll::Ready(Ok(std::mem::take(this.buf).into())),
+            }
+        }
+    }
+}
+
+/// Build a body to send again: the buffered chunk, or an empty body.
+#[inline]
+pub(crate) fn replay<B>(data: Option<&B::Data>) -> B
+where
+    B: Body + Default + From<B::Data>,
+    B::Data: Clone,
+{
+    data.cloned().map_or_else(B::default, B::from)
+}
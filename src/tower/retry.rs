@@ -0,0 +1,39 @@
+//! Retry middleware with browser-safe idempotency rules.
+//!
+//! Retries requests that failed on a broken connection, a timeout or a
+//! retryable status, backing off with full jitter between attempts.
+//!
+//! # Quick Start
+//!
+//! ```no_run
+//! use wreq::Client;
+//! use wreq_util::tower::retry::RetryLayer;
+//!
+//! let client = Client::builder().layer(RetryLayer::new()).build()?;
+//! # Ok::<(), wreq::Error>(())
+//! ```
+//!
+//! # When requests are retried
+//!
+//! - Connection failures before the request was sent are retried for every method
+//! - Connection resets, timeouts and retryable statuses are only retried for idempotent methods
+//!   (`GET`, `HEAD`, `OPTIONS`, `TRACE`, `PUT` and `DELETE`)
+//! - Bodies of a known size, such as bytes or strings, are buffered and sent again. Streamed
+//!   bodies of unknown length cannot be sent twice, so those requests are never retried
+//! - Retries stop when the [`RetryBudget`] runs out, and the last response or error is returned
+//!
+//! # Notes
+//!
+//! - A retry is a copy of the original request, headers and extensions included, so it keeps the
+//!   emulation profile, proxy and other per-request options
+//! - Use [`RetryLayer::on_retry`] to change the identity of the request between attempts
+//! - To wait for `Retry-After`, add a backoff layer before this one, so that it sits inside the
+//!   retry layer and sees every attempt
+
+mod budget;
+mod future;
+mod layer;
+mod policy;
+mod service;
+
+pub use self::{budget::RetryBudget, future::ResponseFuture, layer::RetryLayer, service::Retry};
@@ -0,0 +1,95 @@
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{rand::Rng, tower::delay::jittered_duration};
+
+/// The most retries that can be saved up from deposits.
+const MAX_DEPOSITS: f64 = 100.0;
+
+/// Limits retries to a share of the requests sent, so a failing server is not
+/// hit with a multiple of the normal load.
+///
+/// Every request deposits `ratio` of a retry into the budget and every retry
+/// withdraws one. On top of that, a reserve of `min_per_sec` retries per
+/// second is always available. Clones share the same budget.
+#[derive(Clone, Debug)]
+pub struct RetryBudget {
+    ratio: f64,
+    min_per_sec: u32,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    deposits: f64,
+    reserve: f64,
+    // `None` until the first withdrawal, with the reserve still full.
+    refilled: Option<Instant>,
+}
+
+// ===== impl RetryBudget =====
+
+impl RetryBudget {
+    /// Create a new [`RetryBudget`] allowing retries for `ratio` of the
+    /// requests sent, plus `min_per_sec` retries per second.
+    ///
+    /// `ratio` is clamped to `[0.0, 1000.0]`.
+    pub fn new(ratio: f64, min_per_sec: u32) -> Self {
+        RetryBudget {
+            ratio: ratio.clamp(0.0, 1000.0),
+            min_per_sec,
+            state: Arc::new(Mutex::new(State {
+                deposits: 0.0,
+                reserve: f64::from(min_per_sec),
+                refilled: None,
+            })),
+        }
+    }
+
+    pub(crate) fn deposit(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.deposits = (state.deposits + self.ratio).min(MAX_DEPOSITS);
+    }
+
+    /// Take one retry from the budget, returning `false` if it is exhausted.
+    pub(crate) fn withdraw(&self, now: Instant) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let min_per_sec = f64::from(self.min_per_sec);
+        if let Some(refilled) = state.refilled {
+            let elapsed = now.saturating_duration_since(refilled);
+            state.reserve = (state.reserve + elapsed.as_secs_f64() * min_per_sec).min(min_per_sec);
+        }
+        state.refilled = Some(now);
+
+        if state.deposits >= 1.0 {
+            state.deposits -= 1.0;
+            true
+        } else if state.reserve >= 1.0 {
+            state.reserve -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for RetryBudget {
+    /// Retries for 20% of requests, plus 10 retries per second.
+    #[inline]
+    fn default() -> Self {
+        RetryBudget::new(0.2, 10)
+    }
+}
+
+/// The delay before retry number `attempt`, with full jitter: uniformly
+/// random up to `base * 2^(attempt - 1)`, capped at `max`.
+pub(crate) fn full_jitter(base: Duration, max: Duration, attempt: u32, rng: &Rng) -> Duration {
+    let cap = 1u32
+        .checked_shl(attempt.saturating_sub(1))
+        .and_then(|factor| base.checked_mul(factor))
+        .map_or(max, |backoff| backoff.min(max));
+    jittered_duration(cap / 2, 1.0, rng)
+}
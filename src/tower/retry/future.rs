@@ -0,0 +1,163 @@
+use std::{
+    fmt,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, ready},
+};
+
+use http::{Request, Response, request::Parts};
+use http_body::Body;
+use pin_project_lite::pin_project;
+use tower::{BoxError, Service};
+
+use super::policy::Policy;
+use crate::tower::{
+    replay::{self, Buffer},
+    timer::Sleep,
+};
+
+pin_project! {
+    /// Response future for [`Retry`].
+    ///
+    /// [`Retry`]: super::Retry
+    pub struct ResponseFuture<S, ReqBody>
+    where
+        S: Service<Request<ReqBody>>,
+        ReqBody: Body,
+    {
+        #[pin]
+        state: State<S::Future, ReqBody>,
+        service: S,
+        // `None` when the request body cannot be replayed.
+        parts: Option<Parts>,
+        // The buffered body, sent again on every attempt.
+        body: Option<ReqBody::Data>,
+        attempt: u32,
+        policy: Arc<Policy>,
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    enum State<F, B> {
+        Buffering {
+            #[pin]
+            buffer: Buffer<B>,
+        },
+        Calling {
+            #[pin]
+            response: F,
+        },
+        Waiting {
+            sleep: Sleep,
+        },
+    }
+}
+
+impl<S, ReqBody> ResponseFuture<S, ReqBody>
+where
+    S: Service<Request<ReqBody>>,
+    ReqBody: Body,
+{
+    // Create a new [`ResponseFuture`]
+    //
+    // A body of known size is buffered before the first attempt so it can be
+    // sent again. Other bodies go out right away and are never retried.
+    pub(crate) fn new(mut service: S, req: Request<ReqBody>, policy: Arc<Policy>) -> Self {
+        let (parts, body) = req.into_parts();
+        let (state, parts) = match body.size_hint().exact() {
+            Some(len) if len > 0 => (
+                State::Buffering {
+                    buffer: Buffer::new(body),
+                },
+                Some(parts),
+            ),
+            exact => {
+                let retry_parts = (exact == Some(0)).then(|| parts.clone());
+                let response = service.call(Request::from_parts(parts, body));
+                (State::Calling { response }, retry_parts)
+            }
+        };
+        ResponseFuture {
+            state,
+            service,
+            parts,
+            body: None,
+            attempt: 0,
+            policy,
+        }
+    }
+}
+
+impl<S, ReqBody, ResBody> Future for ResponseFuture<S, ReqBody>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Error: Into<BoxError>,
+    ReqBody: Body + Default + From<ReqBody::Data>,
+    ReqBody::Data: AsRef<[u8]> + From<Vec<u8>> + Clone,
+    ReqBody::Error: Into<BoxError>,
+{
+    type Output = Result<Response<ResBody>, BoxError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Buffering { buffer } => {
+                    let data = ready!(buffer.poll(cx))?;
+                    let parts = this.parts.clone().expect("buffered without request parts");
+                    let req = Request::from_parts(parts, ReqBody::from(data.clone()));
+                    *this.body = Some(data);
+                    let response = this.service.call(req);
+                    this.state.set(State::Calling { response });
+                }
+                StateProj::Calling { response } => {
+                    let result = ready!(response.poll(cx)).map_err(Into::into);
+                    let retry = this.parts.as_ref().is_some_and(|parts| {
+                        this.policy
+                            .should_retry(&parts.method, *this.attempt, &result)
+                    });
+                    if !retry {
+                        return Poll::Ready(result);
+                    }
+
+                    *this.attempt += 1;
+                    let sleep = this.policy.time.sleep(this.policy.backoff(*this.attempt));
+                    this.state.set(State::Waiting { sleep });
+                }
+                StateProj::Waiting { sleep } => {
+                    ready!(sleep.as_mut().poll(cx));
+                    ready!(this.service.poll_ready(cx)).map_err(Into::into)?;
+
+                    let parts = this.parts.as_mut().expect("retried without request parts");
+                    if let Some(on_retry) = &this.policy.on_retry {
+                        on_retry(parts, *this.attempt);
+                    }
+
+                    let body = replay::replay(this.body.as_ref());
+                    let req = Request::from_parts(parts.clone(), body);
+                    let response = this.service.call(req);
+                    this.state.set(State::Calling { response });
+                }
+            }
+        }
+    }
+}
+
+impl<S, ReqBody> fmt::Debug for ResponseFuture<S, ReqBody>
+where
+    S: Service<Request<ReqBody>>,
+    ReqBody: Body,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.state {
+            State::Buffering { .. } => "Buffering",
+            State::Calling { .. } => "Calling",
+            State::Waiting { .. } => "Waiting",
+        };
+        f.debug_struct("ResponseFuture")
+            .field("state", &state)
+            .field("attempt", &self.attempt)
+            .finish()
+    }
+}
@@ -0,0 +1,153 @@
+use std::{sync::Arc, time::Duration};
+
+use http::{StatusCode, request::Parts};
+use tower::Layer;
+
+use super::{RetryBudget, policy::Policy, service::Retry};
+use crate::{
+    rand::Rng,
+    tower::timer::{Time, Timer},
+};
+
+/// A Tower [`Layer`] that retries failed requests when it is safe to do so.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use wreq::Client;
+/// use wreq_util::tower::retry::RetryLayer;
+///
+/// let client = Client::builder()
+///     .layer(RetryLayer::new().max_retries(2).backoff(Duration::from_millis(200), Duration::from_secs(5)))
+///     .build()?;
+/// # Ok::<(), wreq::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryLayer {
+    policy: Policy,
+}
+
+// ===== impl RetryLayer =====
+
+impl RetryLayer {
+    /// Create a new [`RetryLayer`] making up to 3 retries, backing off from
+    /// 100ms up to 10 seconds, on `408`, `429`, `500`, `502`, `503` and `504`
+    /// responses and on connection failures.
+    pub fn new() -> Self {
+        RetryLayer {
+            policy: Policy {
+                max_retries: 3,
+                base: Duration::from_millis(100),
+                max_backoff: Duration::from_secs(10),
+                statuses: vec![
+                    StatusCode::REQUEST_TIMEOUT,
+                    StatusCode::TOO_MANY_REQUESTS,
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    StatusCode::BAD_GATEWAY,
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    StatusCode::GATEWAY_TIMEOUT,
+                ],
+                budget: RetryBudget::default(),
+                rng: Rng::default(),
+                time: Time::default(),
+                on_retry: None,
+            },
+        }
+    }
+
+    /// Set the maximum number of retries per request.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.policy.max_retries = max_retries;
+        self
+    }
+
+    /// Set the exponential backoff between attempts.
+    ///
+    /// Retry `n` waits a random duration of up to `base * 2^(n - 1)`, capped at
+    /// `max`.
+    pub fn backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.policy.base = base;
+        self.policy.max_backoff = max;
+        self
+    }
+
+    /// Set the response statuses that are retried.
+    pub fn statuses<I>(mut self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = StatusCode>,
+    {
+        self.policy.statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Share a [`RetryBudget`] between layers, or use a different one.
+    pub fn budget(mut self, budget: RetryBudget) -> Self {
+        self.policy.budget = budget;
+        self
+    }
+
+    /// Draw backoff jitter from `rng`, e.g. [`Rng::seeded`] for reproducible
+    /// delays.
+    pub fn rng(mut self, rng: Rng) -> Self {
+        self.policy.rng = rng;
+        self
+    }
+
+    /// Measure and sleep with `timer` instead of the timer of the enabled runtime.
+    ///
+    /// See [`timer`](crate::tower::timer) for the available timers.
+    pub fn timer<T: Timer>(mut self, timer: T) -> Self {
+        self.policy.time = Time::new(timer);
+        self
+    }
+
+    /// Run `f` on the request before each retry, with the retry number
+    /// starting at 1.
+    ///
+    /// Changes carry over to later retries. Use this to rotate the identity of
+    /// the request, e.g. its headers, emulation profile or proxy.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use wreq::{Client, Proxy};
+    /// use wreq_util::{Emulation, Profile, tower::retry::RetryLayer};
+    ///
+    /// // Request options set through a builder live in the request extensions,
+    /// // so they can be prepared once and swapped in on retry.
+    /// let fallback = Client::new()
+    ///     .get("http://localhost")
+    ///     .emulation(Emulation::builder().profile(Profile::Firefox150).build())
+    ///     .proxy(Proxy::all("socks5h://127.0.0.1:1080")?)
+    ///     .build()?;
+    ///
+    /// let layer = RetryLayer::new().on_retry(move |parts, _attempt| {
+    ///     parts.headers.extend(fallback.headers().clone());
+    ///     parts.extensions.extend(fallback.extensions().clone());
+    /// });
+    /// ```
+    pub fn on_retry<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&mut Parts, u32) + Send + Sync + 'static,
+    {
+        self.policy.on_retry = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Default for RetryLayer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for RetryLayer {
+    type Service = Retry<S>;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        Retry::new(inner, Arc::new(self.policy.clone()))
+    }
+}
@@ -0,0 +1,132 @@
+use std::{error::Error, fmt, io, sync::Arc, time::Duration};
+
+use http::{Method, Response, StatusCode, request::Parts};
+use tower::BoxError;
+
+use super::budget::{RetryBudget, full_jitter};
+use crate::{rand::Rng, tower::timer::Time};
+
+/// A hook run on the request parts before each retry.
+pub(crate) type OnRetry = Arc<dyn Fn(&mut Parts, u32) + Send + Sync>;
+
+/// Retry settings, shared by the services created by a layer.
+#[derive(Clone)]
+pub(crate) struct Policy {
+    pub(crate) max_retries: u32,
+    pub(crate) base: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) statuses: Vec<StatusCode>,
+    pub(crate) budget: RetryBudget,
+    pub(crate) rng: Rng,
+    pub(crate) time: Time,
+    pub(crate) on_retry: Option<OnRetry>,
+}
+
+/// Why an attempt failed.
+enum Failure {
+    /// The connection could not be established, so the request was never sent.
+    Connect,
+    /// The connection broke or timed out, possibly after the request was sent.
+    Transport,
+    /// The server answered with a retryable status.
+    Status,
+}
+
+// ===== impl Policy =====
+
+impl Policy {
+    /// Whether the outcome of attempt number `attempt` should be retried.
+    pub(crate) fn should_retry<B>(
+        &self,
+        method: &Method,
+        attempt: u32,
+        result: &Result<Response<B>, BoxError>,
+    ) -> bool {
+        if attempt >= self.max_retries {
+            return false;
+        }
+
+        let failure = match result {
+            Ok(res) if self.statuses.contains(&res.status()) => Failure::Status,
+            Ok(_) => return false,
+            Err(err) => match classify(err.as_ref()) {
+                Some(failure) => failure,
+                None => return false,
+            },
+        };
+
+        let safe = match failure {
+            Failure::Connect => true,
+            Failure::Transport | Failure::Status => is_idempotent(method),
+        };
+        safe && self.budget.withdraw(self.time.now())
+    }
+
+    #[inline]
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        full_jitter(self.base, self.max_backoff, attempt, &self.rng)
+    }
+}
+
+impl fmt::Debug for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Policy")
+            .field("max_retries", &self.max_retries)
+            .field("base", &self.base)
+            .field("max_backoff", &self.max_backoff)
+            .field("statuses", &self.statuses)
+            .field("budget", &self.budget)
+            .field("rng", &self.rng)
+            .field("time", &self.time)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+/// Idempotent methods as defined by RFC 9110 §9.2.2.
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE | Method::PUT | Method::DELETE
+    )
+}
+
+/// Walk the error chain looking for a retryable connection failure.
+fn classify(err: &(dyn Error + 'static)) -> Option<Failure> {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<wreq::Error>() {
+            if err.is_connect() {
+                return Some(Failure::Connect);
+            }
+            if err.is_timeout() || err.is_connection_reset() {
+                return Some(Failure::Transport);
+            }
+        }
+
+        if let Some(err) = err.downcast_ref::<io::Error>() {
+            match err.kind() {
+                io::ErrorKind::ConnectionRefused => return Some(Failure::Connect),
+                io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::BrokenPipe
+                | io::ErrorKind::UnexpectedEof
+                | io::ErrorKind::TimedOut => return Some(Failure::Transport),
+                _ => {}
+            }
+        }
+
+        #[cfg(feature = "tokio-rt")]
+        if err.is::<tokio::time::error::Elapsed>() {
+            return Some(Failure::Transport);
+        }
+
+        #[cfg(feature = "compio-rt")]
+        if err.is::<compio::time::Elapsed>() {
+            return Some(Failure::Transport);
+        }
+
+        source = err.source();
+    }
+    None
+}
@@ -0,0 +1,53 @@
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use http::{Request, Response};
+use http_body::Body;
+use tower::{BoxError, Service};
+
+use super::{future::ResponseFuture, policy::Policy};
+use crate::tower::delay::take_ready;
+
+/// A Tower [`Service`] that retries failed requests when it is safe to do so.
+///
+/// Created by [`RetryLayer`](super::RetryLayer).
+#[derive(Clone, Debug)]
+pub struct Retry<S> {
+    inner: S,
+    policy: Arc<Policy>,
+}
+
+// ===== impl Retry =====
+
+impl<S> Retry<S> {
+    #[inline]
+    pub(crate) fn new(inner: S, policy: Arc<Policy>) -> Self {
+        Retry { inner, policy }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for Retry<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone,
+    S::Error: Into<BoxError>,
+    ReqBody: Body + Default + From<ReqBody::Data>,
+    ReqBody::Data: AsRef<[u8]> + From<Vec<u8>> + Clone,
+    ReqBody::Error: Into<BoxError>,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = ResponseFuture<S, ReqBody>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let service = take_ready(&mut self.inner);
+        self.policy.budget.deposit();
+        ResponseFuture::new(service, req, self.policy.clone())
+    }
+}
@@ -0,0 +1,190 @@
+//! Runtime-agnostic timers for the timing middleware.
+//!
+//! The delay, rate limit, backoff and retry layers sleep through a [`Timer`],
+//! so they work on whichever runtime drives the client. By default they use
+//! tokio with the `tokio-rt` feature (on by default), or compio with the
+//! `compio-rt` feature when only that is enabled or when called outside of a
+//! tokio runtime. Pass a timer to `.timer()` on a layer to choose one
+//! explicitly.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use wreq_util::tower::{delay::DelayLayer, timer::CompioTimer};
+//!
+//! let layer = DelayLayer::new(Duration::from_secs(1)).timer(CompioTimer::new());
+//! ```
+
+#[cfg(feature = "compio-rt")]
+use std::task::{Context, Poll};
+use std::{
+    fmt,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// A future returned by [`Timer::sleep`].
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A source of sleeps and the current time, backed by an async runtime.
+pub trait Timer: Send + Sync + 'static {
+    /// Returns a future that resolves after `duration`.
+    fn sleep(&self, duration: Duration) -> Sleep;
+
+    /// Returns the current time, as seen by the runtime.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`Timer`] backed by the tokio runtime.
+///
+/// Follows tokio's clock, so it can be paused and advanced in tests.
+#[cfg(feature = "tokio-rt")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioTimer {
+    _priv: (),
+}
+
+/// A [`Timer`] backed by the compio runtime.
+///
+/// compio is thread-per-core and its sleeps are `!Send`, so they are wrapped
+/// to fit [`Sleep`]. A sleep panics if it is polled or dropped on a thread
+/// other than the one that created it, so requests delayed with this timer
+/// must stay on the compio runtime thread that sent them. This is also the
+/// timer layers pick by default outside of a tokio runtime.
+#[cfg(feature = "compio-rt")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CompioTimer {
+    _priv: (),
+}
+
+/// The timer used by a layer, shared by the services it creates.
+#[derive(Clone, Default)]
+pub(crate) enum Time {
+    /// The timer of the runtime the request is sent on.
+    #[default]
+    Default,
+    Custom(Arc<dyn Timer>),
+}
+
+// ===== impl TokioTimer =====
+
+#[cfg(feature = "tokio-rt")]
+impl TokioTimer {
+    /// Create a new [`TokioTimer`].
+    #[inline]
+    pub const fn new() -> Self {
+        TokioTimer { _priv: () }
+    }
+}
+
+#[cfg(feature = "tokio-rt")]
+impl Timer for TokioTimer {
+    #[inline]
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
+    #[inline]
+    fn now(&self) -> Instant {
+        tokio::time::Instant::now().into_std()
+    }
+}
+
+// ===== impl CompioTimer =====
+
+#[cfg(feature = "compio-rt")]
+impl CompioTimer {
+    /// Create a new [`CompioTimer`].
+    #[inline]
+    pub const fn new() -> Self {
+        CompioTimer { _priv: () }
+    }
+}
+
+#[cfg(feature = "compio-rt")]
+impl Timer for CompioTimer {
+    #[inline]
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(CompioSleep {
+            inner: send_wrapper::SendWrapper::new(Box::pin(compio::time::sleep(duration))),
+        })
+    }
+}
+
+/// A compio sleep made `Send`.
+///
+/// compio futures are `!Send` (thread-per-core). `SendWrapper` panics if the
+/// sleep is polled or dropped on another thread, see [`CompioTimer`].
+#[cfg(feature = "compio-rt")]
+struct CompioSleep {
+    inner: send_wrapper::SendWrapper<Pin<Box<dyn Future<Output = ()>>>>,
+}
+
+#[cfg(feature = "compio-rt")]
+impl Future for CompioSleep {
+    type Output = ();
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().inner.as_mut().poll(cx)
+    }
+}
+
+// ===== impl Time =====
+
+impl Time {
+    #[inline]
+    pub(crate) fn new<T: Timer>(timer: T) -> Self {
+        Time::Custom(Arc::new(timer))
+    }
+
+    #[inline]
+    pub(crate) fn sleep(&self, duration: Duration) -> Sleep {
+        match self {
+            Time::Default => default_timer().sleep(duration),
+            Time::Custom(timer) => timer.sleep(duration),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn now(&self) -> Instant {
+        match self {
+            Time::Default => default_timer().now(),
+            Time::Custom(timer) => timer.now(),
+        }
+    }
+}
+
+impl fmt::Debug for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Time::Default => f.write_str("Default"),
+            Time::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+/// tokio, unless `compio-rt` is enabled and no tokio runtime is running.
+#[cfg(feature = "tokio-rt")]
+fn default_timer() -> &'static dyn Timer {
+    static TOKIO: TokioTimer = TokioTimer::new();
+
+    #[cfg(feature = "compio-rt")]
+    if tokio::runtime::Handle::try_current().is_err() {
+        static COMPIO: CompioTimer = CompioTimer::new();
+        return &COMPIO;
+    }
+
+    &TOKIO
+}
+
+/// compio, the only runtime enabled.
+#[cfg(not(feature = "tokio-rt"))]
+fn default_timer() -> &'static dyn Timer {
+    static COMPIO: CompioTimer = CompioTimer::new();
+    &COMPIO
+}
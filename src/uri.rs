@@ -0,0 +1,197 @@
+use std::fmt::Write;
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+use std::net::IpAddr;
+
+use http::Uri;
+
+/// Resolve a URI reference, such as a `Location` header value, against `base`.
+///
+/// Follows RFC 3986 §5.2. Fragments are dropped, and bytes that are not
+/// allowed in a URI are percent-encoded the way browsers do before sending.
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+pub(crate) fn resolve(base: &Uri, reference: &str) -> Option<Uri> {
+    let reference = reference.trim();
+    let reference = reference.split_once('#').map_or(reference, |(r, _)| r);
+    let reference = percent_encode(reference);
+
+    let base_scheme = base.scheme_str()?;
+    let base_authority = base.authority()?.as_str();
+
+    let target = if let Some((scheme, rest)) = split_scheme(&reference) {
+        let (authority, path_and_query) = match rest.strip_prefix("//") {
+            Some(rest) => rest.split_at(rest.find(['/', '?']).unwrap_or(rest.len())),
+            None => return None,
+        };
+        let (path, query) = split_query(path_and_query);
+        build(scheme, authority, &remove_dot_segments(path), query)
+    } else if let Some(rest) = reference.strip_prefix("//") {
+        let (authority, path_and_query) =
+            rest.split_at(rest.find(['/', '?']).unwrap_or(rest.len()));
+        let (path, query) = split_query(path_and_query);
+        build(base_scheme, authority, &remove_dot_segments(path), query)
+    } else {
+        let (path, query) = split_query(&reference);
+        let base_path = match base.path() {
+            "" => "/",
+            path => path,
+        };
+
+        if path.is_empty() {
+            let query = query.or(if reference.is_empty() {
+                base.query()
+            } else {
+                None
+            });
+            build(base_scheme, base_authority, base_path, query)
+        } else if path.starts_with('/') {
+            build(
+                base_scheme,
+                base_authority,
+                &remove_dot_segments(path),
+                query,
+            )
+        } else {
+            let dir = &base_path[..base_path.rfind('/').map_or(0, |i| i + 1)];
+            let merged = format!("{dir}{path}");
+            build(
+                base_scheme,
+                base_authority,
+                &remove_dot_segments(&merged),
+                query,
+            )
+        }
+    };
+
+    target.parse().ok()
+}
+
+/// Returns the ASCII serialization of the origin of `uri`, e.g. `https://example.com`.
+pub(crate) fn origin(uri: &Uri) -> Option<String> {
+    let scheme = uri.scheme_str()?;
+    let host = uri.host()?;
+    let mut origin = format!("{scheme}://{host}");
+    if let Some(port) = uri
+        .port_u16()
+        .filter(|port| Some(*port) != default_port(scheme))
+    {
+        let _ = write!(origin, ":{port}");
+    }
+    Some(origin)
+}
+
+/// Whether two URIs share scheme, host and port.
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+pub(crate) fn same_origin(a: &Uri, b: &Uri) -> bool {
+    let port = |uri: &Uri| {
+        uri.port_u16()
+            .or_else(|| uri.scheme_str().and_then(default_port))
+    };
+
+    a.scheme() == b.scheme()
+        && a.host().map(str::to_ascii_lowercase) == b.host().map(str::to_ascii_lowercase)
+        && port(a) == port(b)
+}
+
+/// Whether two URIs are schemefully same-site.
+///
+/// Without a public suffix list the registrable domain is approximated by the
+/// last two labels of the host, which is exact for the common `example.com`
+/// case but treats hosts under multi-label suffixes such as `co.uk` as the
+/// same site. IP addresses only match themselves.
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+pub(crate) fn same_site(a: &Uri, b: &Uri) -> bool {
+    fn site(host: &str) -> String {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        if host.trim_matches(['[', ']']).parse::<IpAddr>().is_ok() {
+            return host;
+        }
+        let mut labels = host.rsplitn(3, '.');
+        match (labels.next(), labels.next()) {
+            (Some(tld), Some(domain)) => format!("{domain}.{tld}"),
+            _ => host,
+        }
+    }
+
+    a.scheme() == b.scheme()
+        && match (a.host(), b.host()) {
+            (Some(a), Some(b)) => site(a) == site(b),
+            _ => false,
+        }
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "http" | "ws" => Some(80),
+        "https" | "wss" => Some(443),
+        _ => None,
+    }
+}
+
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+fn split_scheme(reference: &str) -> Option<(&str, &str)> {
+    let (scheme, rest) = reference.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some((scheme, rest))
+}
+
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+fn split_query(reference: &str) -> (&str, Option<&str>) {
+    match reference.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (reference, None),
+    }
+}
+
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+fn build(scheme: &str, authority: &str, path: &str, query: Option<&str>) -> String {
+    let path = if path.is_empty() { "/" } else { path };
+    match query {
+        Some(query) => format!("{scheme}://{authority}{path}?{query}"),
+        None => format!("{scheme}://{authority}{path}"),
+    }
+}
+
+/// Remove `.` and `..` segments as described in RFC 3986 §5.2.4.
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+fn remove_dot_segments(path: &str) -> String {
+    let mut output: Vec<&str> = Vec::new();
+    let segments = path.split('/').skip(usize::from(path.starts_with('/')));
+    let mut trailing_slash = false;
+
+    for segment in segments {
+        trailing_slash = false;
+        match segment {
+            "." => trailing_slash = true,
+            ".." => {
+                output.pop();
+                trailing_slash = true;
+            }
+            segment => output.push(segment),
+        }
+    }
+
+    let mut result = String::with_capacity(path.len());
+    for segment in &output {
+        result.push('/');
+        result.push_str(segment);
+    }
+    if trailing_slash || result.is_empty() {
+        result.push('/');
+    }
+    result
+}
+
+#[cfg(any(feature = "tower-redirect", feature = "emulation-session"))]
+fn percent_encode(reference: &str) -> String {
+    let mut encoded = String::with_capacity(reference.len());
+    for byte in reference.bytes() {
+        if byte.is_ascii_graphic() && !matches!(byte, b'"' | b'<' | b'>' | b'`' | b'{' | b'}') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
@@ -3,7 +3,7 @@ mod support;
 
 use support::server;
 use wreq::Client;
-use wreq_util::{Emulation, Platform};
+use wreq_util::{App, Emulation, Platform, RequestKind, Rng};
 
 #[tokio::test]
 async fn test_client_emulation_device() {
@@ -49,3 +49,423 @@ async fn test_client_emulation_device() {
 
     assert_eq!(res.status(), wreq::StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_client_emulation_android() {
+    let server = server::http(move |req| async move {
+        let headers = req.headers();
+        let body = format!(
+            "{}\n{}\n{}",
+            headers["user-agent"].to_str().unwrap(),
+            headers["sec-ch-ua-mobile"].to_str().unwrap(),
+            headers["sec-ch-ua-platform"].to_str().unwrap(),
+        );
+        http::Response::new(wreq::Body::from(body))
+    });
+
+    let url = format!("http://{}/ua", server.addr());
+    for profile in [
+        Emulation::Chrome105,
+        Emulation::Chrome131,
+        Emulation::Chrome149,
+    ] {
+        let text = Client::builder()
+            .emulation(
+                Emulation::builder()
+                    .profile(profile)
+                    .platform(Platform::Android)
+                    .build(),
+            )
+            .build()
+            .expect("Unable to build client")
+            .get(&url)
+            .send()
+            .await
+            .expect("request")
+            .text()
+            .await
+            .expect("text");
+
+        let mut lines = text.lines();
+        let ua = lines.next().unwrap();
+        assert!(ua.starts_with("Mozilla/5.0 (Linux; Android "), "{ua}");
+        assert!(ua.ends_with(" Mobile Safari/537.36"), "{ua}");
+        assert_eq!(lines.next(), Some("?1"));
+        assert_eq!(lines.next(), Some("\"Android\""));
+    }
+}
+
+#[tokio::test]
+async fn test_client_emulation_header_order() {
+    let server = server::http(move |req| async move {
+        let names = req
+            .headers()
+            .keys()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        http::Response::new(wreq::Body::from(names))
+    });
+
+    let url = format!("http://{}/order", server.addr());
+    let res = Client::builder()
+        .emulation(
+            Emulation::builder()
+                .profile(Emulation::Chrome133)
+                .platform(Platform::Linux)
+                .build(),
+        )
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .header("cookie", "a=1")
+        .header("referer", "https://example.com/")
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+
+    let body = res.text().await.expect("text");
+    let names = body.split(',').collect::<Vec<_>>();
+    let position = |name: &str| {
+        names
+            .iter()
+            .position(|n| *n == name)
+            .unwrap_or_else(|| panic!("missing header {name}: {body}"))
+    };
+
+    assert!(position("sec-ch-ua") < position("user-agent"));
+    assert!(position("sec-fetch-dest") < position("referer"));
+    assert!(position("referer") < position("accept-language"));
+    assert!(position("accept-language") < position("cookie"));
+    assert!(position("cookie") < position("priority"));
+}
+
+#[tokio::test]
+async fn test_client_emulation_request_kind() {
+    let server = server::http(move |req| async move {
+        let headers = req.headers();
+        assert_eq!(headers["sec-fetch-mode"], "cors");
+        assert_eq!(headers["sec-fetch-dest"], "empty");
+        assert_eq!(headers["sec-fetch-site"], "same-origin");
+        assert_eq!(headers["accept"], "*/*");
+        assert_eq!(headers["content-type"], "application/json");
+        assert_eq!(headers["priority"], "u=1, i");
+        assert!(!headers.contains_key("sec-fetch-user"));
+        assert!(!headers.contains_key("upgrade-insecure-requests"));
+        http::Response::default()
+    });
+
+    let url = format!("http://{}/api", server.addr());
+    let emulation = Emulation::builder()
+        .profile(Emulation::Chrome147)
+        .request_kind(RequestKind::JsonFetch)
+        .build();
+    let headers = emulation.request_headers();
+    let res = Client::builder()
+        .emulation(emulation)
+        .build()
+        .expect("Unable to build client")
+        .post(&url)
+        .headers(headers)
+        .body("{}")
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_client_emulation_request_headers_not_default() {
+    let server = server::http(move |req| async move {
+        assert!(!req.headers().contains_key("content-type"));
+        assert!(!req.headers().contains_key("cache-control"));
+        http::Response::default()
+    });
+
+    let emulation = Emulation::builder()
+        .profile(Emulation::Chrome147)
+        .request_kind(RequestKind::FormSubmit)
+        .build();
+
+    let headers = emulation.request_headers();
+    assert_eq!(headers["content-type"], "application/x-www-form-urlencoded");
+    assert_eq!(headers["cache-control"], "max-age=0");
+
+    let res = Client::builder()
+        .emulation(emulation)
+        .build()
+        .expect("Unable to build client")
+        .get(format!("http://{}/form", server.addr()))
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+}
+
+#[test]
+fn test_client_emulation_request_headers_per_family() {
+    let request_headers = |profile| {
+        Emulation::builder()
+            .profile(profile)
+            .request_kind(RequestKind::FormSubmit)
+            .build()
+            .request_headers()
+    };
+
+    let headers = request_headers(Emulation::Opera131);
+    assert_eq!(headers["cache-control"], "max-age=0");
+
+    let headers = request_headers(Emulation::Firefox151);
+    assert_eq!(headers["content-type"], "application/x-www-form-urlencoded");
+    assert!(!headers.contains_key("cache-control"));
+
+    assert!(request_headers(Emulation::OkHttp5).is_empty());
+}
+
+#[tokio::test]
+async fn test_client_emulation_seeded_random() {
+    let server = server::http(move |req| async move {
+        let user_agent = req.headers()["user-agent"].clone();
+        http::Response::new(wreq::Body::from(user_agent.as_bytes().to_vec()))
+    });
+
+    let url = format!("http://{}/", server.addr());
+    let mut user_agents = Vec::new();
+    for _ in 0..2 {
+        let rng = Rng::seeded(7);
+        for emulation in [
+            Emulation::random_with(&rng),
+            Emulation::weighted_random_with(&rng),
+        ] {
+            let text = Client::builder()
+                .emulation(emulation)
+                .build()
+                .expect("Unable to build client")
+                .get(&url)
+                .send()
+                .await
+                .expect("request")
+                .text()
+                .await
+                .expect("body");
+            user_agents.push(text);
+        }
+    }
+
+    assert_eq!(user_agents[..2], user_agents[2..]);
+}
+
+#[tokio::test]
+async fn test_client_emulation_webview() {
+    let server = server::http(move |req| async move {
+        assert!(!req.headers().contains_key("priority"));
+        let user_agent = req.headers()["user-agent"].clone();
+        http::Response::new(wreq::Body::from(user_agent.as_bytes().to_vec()))
+    });
+
+    let url = format!("http://{}/webview", server.addr());
+    let res = Client::builder()
+        .emulation(Emulation::InstagramIos26)
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    let user_agent = res.text().await.expect("text");
+    assert!(
+        user_agent.contains("Mobile/15E148 Instagram "),
+        "{user_agent}"
+    );
+    assert!(!user_agent.contains("Safari/"), "{user_agent}");
+}
+
+#[tokio::test]
+async fn test_client_emulation_cfnetwork_app() {
+    let server = server::http(move |req| async move {
+        let user_agent = req.headers()["user-agent"].clone();
+        http::Response::new(wreq::Body::from(user_agent.as_bytes().to_vec()))
+    });
+
+    let url = format!("http://{}/app", server.addr());
+    let res = Client::builder()
+        .emulation(
+            Emulation::builder()
+                .profile(Emulation::CFNetwork3860)
+                .app(App::new("Acme", "2.4.1").expect("valid app"))
+                .build(),
+        )
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert_eq!(
+        res.text().await.expect("text"),
+        "Acme/2.4.1 CFNetwork/3860.100.1 Darwin/25.0.0"
+    );
+}
+
+#[tokio::test]
+async fn test_client_emulation_okhttp_app() {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.headers()["x-client"], "acme-android");
+        let user_agent = req.headers()["user-agent"].clone();
+        http::Response::new(wreq::Body::from(user_agent.as_bytes().to_vec()))
+    });
+
+    let url = format!("http://{}/app", server.addr());
+    let app = App::new("Acme", "2.4.1")
+        .expect("valid app")
+        .android_sdk(34)
+        .device_model("Pixel 8")
+        .expect("valid device model")
+        .header(
+            http::HeaderName::from_static("x-client"),
+            http::HeaderValue::from_static("acme-android"),
+        );
+    let res = Client::builder()
+        .emulation(
+            Emulation::builder()
+                .profile(Emulation::OkHttp5)
+                .app(app)
+                .build(),
+        )
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert_eq!(
+        res.text().await.expect("text"),
+        "Acme/2.4.1 (Android SDK 34; Pixel 8) OkHttp/5.0.0-alpha2"
+    );
+}
+
+#[test]
+fn test_app_rejects_invalid_identity() {
+    assert!(App::new("", "1.0").is_err());
+    assert!(App::new("Acme App", "1.0").is_err());
+    assert!(App::new("Acme", "2.4/1").is_err());
+    assert!(App::new("Acme", "(2.4.1)").is_err());
+    assert!(App::new("Acme\n", "2.4.1").is_err());
+
+    let app = App::new("Acme", "2.4.1").expect("valid app");
+    assert!(app.clone().device_model("Pixel (8)").is_err());
+    assert!(app.clone().device_model("Pixel; 8").is_err());
+    assert!(app.device_model("Pixel 8").is_ok());
+}
+
+#[tokio::test]
+async fn test_client_emulation_cfnetwork_without_app() {
+    let server = server::http(move |req| async move {
+        let user_agent = req.headers()["user-agent"].clone();
+        http::Response::new(wreq::Body::from(user_agent.as_bytes().to_vec()))
+    });
+
+    let url = format!("http://{}/app", server.addr());
+    let res = Client::builder()
+        .emulation(Emulation::CFNetwork3860)
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert_eq!(
+        res.text().await.expect("text"),
+        "CFNetwork/3860.100.1 Darwin/25.0.0"
+    );
+}
+
+#[tokio::test]
+async fn test_client_emulation_go_http1() {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.version(), http::Version::HTTP_11);
+        let user_agent = req.headers()["user-agent"].clone();
+        http::Response::new(wreq::Body::from(user_agent.as_bytes().to_vec()))
+    });
+
+    let url = format!("http://{}/go", server.addr());
+    let res = Client::builder()
+        .emulation(
+            Emulation::builder()
+                .profile(Emulation::Go1_24)
+                .http2(false)
+                .build(),
+        )
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert_eq!(res.text().await.expect("text"), "Go-http-client/1.1");
+}
+
+#[tokio::test]
+async fn test_client_emulation_dotnet_no_default_headers() {
+    let server = server::http(move |req| async move {
+        assert!(req.headers().get("user-agent").is_none());
+        assert!(req.headers().get("accept").is_none());
+        http::Response::default()
+    });
+
+    let url = format!("http://{}/dotnet", server.addr());
+    let res = Client::builder()
+        .emulation(Emulation::DotNetHttpClient8)
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_client_emulation_tor_browser() {
+    let server = server::http(move |req| async move {
+        assert_eq!(req.headers()["accept-language"], "en-US,en;q=0.5");
+        let user_agent = req.headers()["user-agent"].clone();
+        http::Response::new(wreq::Body::from(user_agent.as_bytes().to_vec()))
+    });
+
+    let url = format!("http://{}/tor", server.addr());
+    let res = Client::builder()
+        .emulation(
+            Emulation::builder()
+                .profile(Emulation::TorBrowser14_5)
+                .platform(Platform::Linux)
+                .build(),
+        )
+        .build()
+        .expect("Unable to build client")
+        .get(&url)
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert_eq!(
+        res.text().await.expect("text"),
+        "Mozilla/5.0 (Windows NT 10.0; rv:128.0) Gecko/20100101 Firefox/128.0"
+    );
+}
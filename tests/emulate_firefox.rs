@@ -1,7 +1,7 @@
 #[macro_use]
 mod support;
 
-use wreq_util::Emulation;
+use wreq_util::{Emulation, Platform};
 
 // Enabling certain extensions(ECH) will change the length during encryption. This is because TLS will
 // automatically use padding to fill the data and add a padding extension. At this time, the ja4
@@ -90,3 +90,25 @@ test_emulation!(
     ["t13d1717h2_5b57614c22b0_3cbfd9057e0d"],
     "6ea73faa8fc5aac76bded7bd238f6433"
 );
+
+// Private browsing and Android apply on top of the release profile and match
+// the dedicated 135 captures.
+test_emulation!(
+    test_firefox_135_private,
+    Emulation::builder()
+        .profile(Emulation::Firefox135)
+        .private(true)
+        .build(),
+    ["t13d1715h2_5b57614c22b0_a54fffd0eb61"],
+    "6ea73faa8fc5aac76bded7bd238f6433"
+);
+
+test_emulation!(
+    test_firefox_135_android,
+    Emulation::builder()
+        .profile(Emulation::Firefox135)
+        .platform(Platform::Android)
+        .build(),
+    ["t13d1716h2_5b57614c22b0_eeeea6562960"],
+    "41a06cadb1c6385e6d08c8d0dbbea818"
+);
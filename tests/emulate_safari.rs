@@ -149,3 +149,17 @@ test_emulation!(
     ["t13d2014h2_a09f3c656075_e42f34c56612"],
     "773906b0efdefa24a7f2b8eb6985bf37"
 );
+
+test_emulation!(
+    test_webview_ios_26,
+    Emulation::WebViewIos26,
+    ["t13d2013h2_a09f3c656075_7f0f34a4126d"],
+    "c52879e43202aeb92740be6e8c86ea96"
+);
+
+test_emulation!(
+    test_instagram_ios_26,
+    Emulation::InstagramIos26,
+    ["t13d2013h2_a09f3c656075_7f0f34a4126d"],
+    "c52879e43202aeb92740be6e8c86ea96"
+);
@@ -0,0 +1,29 @@
+#[macro_use]
+mod support;
+
+use wreq_util::Emulation;
+
+// Enabling certain extensions will change the length during encryption. This is because TLS will
+// automatically use padding to fill the data and add a padding extension. At this time, the ja4
+// fingerprint will change.
+
+test_emulation!(
+    test_samsung_internet27,
+    Emulation::SamsungInternet27,
+    ["t13d1516h2_8daaf6152771_02713d6af862"],
+    "52d84b11737d980aef856699f885ca86"
+);
+
+test_emulation!(
+    test_samsung_internet28,
+    Emulation::SamsungInternet28,
+    ["t13d1516h2_8daaf6152771_02713d6af862"],
+    "52d84b11737d980aef856699f885ca86"
+);
+
+test_emulation!(
+    test_samsung_internet29,
+    Emulation::SamsungInternet29,
+    ["t13d1516h2_8daaf6152771_d8a2da3f94cd"],
+    "52d84b11737d980aef856699f885ca86"
+);
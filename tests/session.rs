@@ -0,0 +1,99 @@
+#![cfg(not(target_arch = "wasm32"))]
+mod support;
+
+use support::server;
+use wreq::Client;
+use wreq_util::{
+    Emulation,
+    emulate::session::{BrowserSession, Destination},
+};
+
+#[tokio::test]
+async fn test_session_page_load() {
+    let server = server::http(move |req| async move {
+        let header = |name: &str| {
+            req.headers()
+                .get(name)
+                .map(|value| value.to_str().unwrap().to_owned())
+        };
+
+        match req.uri().path() {
+            "/" => {
+                assert_eq!(header("sec-fetch-dest").as_deref(), Some("document"));
+                assert_eq!(header("sec-fetch-mode").as_deref(), Some("navigate"));
+                http::Response::builder()
+                    .header("content-type", "text/html; charset=utf-8")
+                    .body(wreq::Body::from(
+                        r#"<html><head><link rel="stylesheet" href="/main.css"><script src="app.js"></script></head></html>"#,
+                    ))
+                    .unwrap()
+            }
+            "/main.css" => {
+                assert_eq!(header("accept").as_deref(), Some("text/css,*/*;q=0.1"));
+                assert_eq!(header("sec-fetch-dest").as_deref(), Some("style"));
+                assert_eq!(header("sec-fetch-mode").as_deref(), Some("no-cors"));
+                assert_eq!(header("sec-fetch-site").as_deref(), Some("same-origin"));
+                assert_eq!(header("priority").as_deref(), Some("u=0"));
+                assert!(header("sec-fetch-user").is_none());
+                assert!(header("content-type").is_none());
+                assert!(header("referer").is_some_and(|referer| referer.ends_with('/')));
+                http::Response::default()
+            }
+            "/app.js" => {
+                assert_eq!(header("accept").as_deref(), Some("*/*"));
+                assert_eq!(header("sec-fetch-dest").as_deref(), Some("script"));
+                http::Response::default()
+            }
+            "/favicon.ico" => http::Response::builder()
+                .status(404)
+                .body(wreq::Body::default())
+                .unwrap(),
+            path => panic!("unexpected request to {path}"),
+        }
+    });
+
+    let session = BrowserSession::builder()
+        .client(Client::new())
+        .emulation(Emulation::builder().profile(Emulation::Chrome147).build())
+        .build();
+
+    let page = session
+        .load(format!("http://{}/", server.addr()))
+        .await
+        .expect("page load");
+
+    assert_eq!(page.status(), wreq::StatusCode::OK);
+
+    let subresources = page
+        .subresources()
+        .iter()
+        .map(|subresource| {
+            (
+                subresource.uri().path().to_owned(),
+                subresource.destination(),
+                subresource.result().expect("subresource"),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        subresources,
+        [
+            (
+                "/main.css".to_owned(),
+                Destination::Style,
+                wreq::StatusCode::OK
+            ),
+            (
+                "/app.js".to_owned(),
+                Destination::Script,
+                wreq::StatusCode::OK
+            ),
+            (
+                "/favicon.ico".to_owned(),
+                Destination::Image,
+                wreq::StatusCode::NOT_FOUND
+            ),
+        ]
+    );
+}
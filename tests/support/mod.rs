@@ -49,6 +49,30 @@ macro_rules! test_emulation {
             }
             assert!(conditional);
 
+            tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+        }
+    };
+    ($test_name:ident, $emulation:expr, $akamai_hash:expr) => {
+        #[tokio::test]
+        async fn $test_name() {
+            let _permit = crate::support::TEST_SEMAPHORE.acquire().await.unwrap();
+
+            let resp = crate::support::CLIENT
+                .get("https://tls.browserleaks.com/")
+                .emulation($emulation)
+                .send()
+                .await
+                .unwrap();
+
+            assert_eq!(resp.status(), wreq::StatusCode::OK);
+            let content = resp.text().await.unwrap();
+
+            let conditional = content.contains($akamai_hash);
+            if !conditional {
+                println!("{}", content);
+            }
+            assert!(conditional);
+
             tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
         }
     };
@@ -0,0 +1,202 @@
+#![cfg(not(target_arch = "wasm32"))]
+mod support;
+
+use std::{
+    convert::Infallible,
+    future::ready,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use http::{Request, Response, StatusCode};
+use support::server;
+use tokio::time::Instant;
+use tower::{BoxError, Layer, Service, ServiceExt, service_fn};
+use wreq::Client;
+use wreq_util::tower::backoff::BackoffLayer;
+
+type Calls = Arc<Mutex<Vec<Duration>>>;
+
+/// A mock service that records when each request is dispatched to it,
+/// relative to the start of the test.
+///
+/// The response status is taken from the request path and its headers from
+/// the query, e.g. `/429?retry-after=5`.
+fn scripted() -> (
+    impl Service<Request<()>, Response = Response<()>, Error = Infallible> + Clone,
+    Calls,
+) {
+    let start = Instant::now();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let service = service_fn({
+        let calls = calls.clone();
+        move |req: Request<()>| {
+            calls.lock().unwrap().push(start.elapsed());
+
+            let mut res = Response::builder().status(&req.uri().path()[1..]);
+            for pair in req.uri().query().unwrap_or_default().split('&') {
+                if let Some((name, value)) = pair.split_once('=') {
+                    res = res.header(name, value.replace("%20", " "));
+                }
+            }
+            ready(Ok(res.body(()).unwrap()))
+        }
+    });
+    (service, calls)
+}
+
+async fn send<S>(service: &mut S, uri: &str) -> StatusCode
+where
+    S: Service<Request<()>, Response = Response<()>, Error = BoxError>,
+{
+    let req = Request::get(uri).body(()).unwrap();
+    let res = service.ready().await.unwrap().call(req).await.unwrap();
+    res.status()
+}
+
+fn secs(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_backoff_honors_retry_after_seconds() {
+    let (service, calls) = scripted();
+    let mut service = BackoffLayer::new().layer(service);
+
+    send(&mut service, "http://a.test/429?retry-after=5").await;
+    send(&mut service, "http://b.test/200").await;
+    send(&mut service, "http://a.test/200").await;
+
+    assert_eq!(*calls.lock().unwrap(), [secs(0), secs(0), secs(5)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_backoff_honors_retry_after_date() {
+    let (service, calls) = scripted();
+    let mut service = BackoffLayer::new().layer(service);
+
+    // Measured against the server clock, not the local one.
+    send(
+        &mut service,
+        "http://a.test/503?date=Sun,%2006%20Nov%201994%2008:49:37%20GMT\
+         &retry-after=Sun,%2006%20Nov%201994%2008:49:44%20GMT",
+    )
+    .await;
+    send(&mut service, "http://a.test/200").await;
+
+    assert_eq!(*calls.lock().unwrap(), [secs(0), secs(7)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_backoff_doubles_and_decays() {
+    let (service, calls) = scripted();
+    let mut service = BackoffLayer::new()
+        .base(secs(1))
+        .max(secs(4))
+        .half_life(secs(10))
+        .layer(service);
+
+    for _ in 0..4 {
+        send(&mut service, "http://a.test/429").await;
+    }
+    // Backed off by 1s, 2s and 4s, capped at 4s.
+    assert_eq!(*calls.lock().unwrap(), [secs(0), secs(1), secs(3), secs(7)]);
+
+    // Requests stay spaced by the backoff, which halves every 10 seconds.
+    for _ in 0..6 {
+        send(&mut service, "http://a.test/200").await;
+    }
+    assert_eq!(
+        calls.lock().unwrap()[4..],
+        [secs(11), secs(15), secs(19), secs(23), secs(25), secs(27)]
+    );
+
+    // Once it drops below the base, requests flow freely again.
+    tokio::time::sleep(secs(30)).await;
+    let start = Instant::now();
+    send(&mut service, "http://a.test/200").await;
+    send(&mut service, "http://a.test/200").await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_backoff_waits_for_exhausted_quota() {
+    let (service, calls) = scripted();
+    let mut service = BackoffLayer::new().layer(service);
+
+    send(
+        &mut service,
+        "http://a.test/200?x-ratelimit-remaining=1&x-ratelimit-reset=30",
+    )
+    .await;
+    send(
+        &mut service,
+        "http://a.test/200?ratelimit-remaining=0&ratelimit-reset=3",
+    )
+    .await;
+    send(&mut service, "http://a.test/200").await;
+    send(&mut service, "http://a.test/200").await;
+
+    assert_eq!(*calls.lock().unwrap(), [secs(0), secs(0), secs(3), secs(3)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_backoff_caps_absurd_reset() {
+    let (service, calls) = scripted();
+    let mut service = BackoffLayer::new().max(secs(60)).layer(service);
+
+    send(
+        &mut service,
+        "http://a.test/200?x-ratelimit-remaining=0&x-ratelimit-reset=1e20",
+    )
+    .await;
+    send(
+        &mut service,
+        "http://a.test/200?ratelimit-remaining=0&ratelimit-reset=1e15",
+    )
+    .await;
+    send(&mut service, "http://a.test/200").await;
+
+    // A reset beyond `Duration` is ignored, and one far in the future is capped
+    // at the maximum backoff.
+    assert_eq!(*calls.lock().unwrap(), [secs(0), secs(0), secs(60)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_backoff_with_client() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let server = server::http({
+        let hits = hits.clone();
+        move |_req| {
+            let hits = hits.clone();
+            async move {
+                if hits.fetch_add(1, Ordering::SeqCst) == 0 {
+                    http::Response::builder()
+                        .status(429)
+                        .header("retry-after", "2")
+                        .body(wreq::Body::default())
+                        .unwrap()
+                } else {
+                    http::Response::default()
+                }
+            }
+        }
+    });
+
+    let client = Client::builder()
+        .layer(BackoffLayer::new())
+        .build()
+        .expect("Unable to build client");
+
+    let url = format!("http://{}/", server.addr());
+    let res = client.get(&url).send().await.expect("request");
+    assert_eq!(res.status(), wreq::StatusCode::TOO_MANY_REQUESTS);
+
+    let start = Instant::now();
+    let res = client.get(&url).send().await.expect("request");
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert!(start.elapsed() >= secs(2));
+}
@@ -0,0 +1,216 @@
+#![cfg(not(target_arch = "wasm32"))]
+mod support;
+
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use http::{Request, Response};
+use support::server;
+use tokio::time::Instant;
+use tower::{BoxError, Layer, Service, ServiceExt, service_fn};
+use wreq::Client;
+use wreq_util::tower::concurrency::{ConcurrencyLimitLayer, Global};
+
+type Calls = Arc<Mutex<Vec<(String, Duration)>>>;
+
+/// A mock service that records when each request is dispatched to it,
+/// relative to the start of the test, and answers one second later.
+fn recording() -> (
+    impl Service<Request<()>, Response = Response<()>, Error = BoxError, Future: Send>
+    + Clone
+    + Send
+    + 'static,
+    Calls,
+) {
+    let start = Instant::now();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let service = service_fn({
+        let calls = calls.clone();
+        move |req: Request<()>| {
+            calls
+                .lock()
+                .unwrap()
+                .push((req.uri().to_string(), start.elapsed()));
+            async {
+                tokio::time::sleep(secs(1)).await;
+                Ok(Response::new(()))
+            }
+        }
+    });
+    (service, calls)
+}
+
+fn get(uri: &str) -> Request<()> {
+    Request::get(uri).body(()).unwrap()
+}
+
+/// Call the service with each request in order, then wait for all of them.
+async fn send_all<S>(service: &mut S, uris: &[&str])
+where
+    S: Service<Request<()>, Response = Response<()>, Error = BoxError>,
+    S::Future: Send + 'static,
+{
+    let mut handles = Vec::new();
+    for uri in uris {
+        handles.push(tokio::spawn(service.ready().await.unwrap().call(get(uri))));
+    }
+    for handle in handles {
+        handle.await.unwrap().unwrap();
+    }
+}
+
+/// Not ready the first time it is polled after `stall` is set.
+#[derive(Clone)]
+struct Stalling<S> {
+    inner: S,
+    stall: Arc<AtomicBool>,
+}
+
+impl<S: Service<Req>, Req> Service<Req> for Stalling<S> {
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.stall.swap(false, Ordering::SeqCst) {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        self.inner.call(req)
+    }
+}
+
+fn secs(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_limits_in_flight_requests_per_host() {
+    let (service, calls) = recording();
+    let mut service = ConcurrencyLimitLayer::new(2).layer(service);
+
+    send_all(
+        &mut service,
+        &[
+            "http://a.test/1",
+            "http://a.test/2",
+            "http://a.test/3",
+            "http://b.test/1",
+            "http://A.test/4",
+            "http://a.test/5",
+        ],
+    )
+    .await;
+
+    // Queued requests go out in call order as slots free up.
+    assert_eq!(
+        calls.lock().unwrap().as_slice(),
+        [
+            ("http://a.test/1".to_owned(), secs(0)),
+            ("http://a.test/2".to_owned(), secs(0)),
+            ("http://b.test/1".to_owned(), secs(0)),
+            ("http://a.test/3".to_owned(), secs(1)),
+            ("http://A.test/4".to_owned(), secs(1)),
+            ("http://a.test/5".to_owned(), secs(2)),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_slot_is_released_when_response_arrives() {
+    let (service, calls) = recording();
+    let mut service = ConcurrencyLimitLayer::new(1).layer(service);
+
+    let first = service.ready().await.unwrap().call(get("http://a.test/1"));
+    let second = tokio::spawn(service.ready().await.unwrap().call(get("http://a.test/2")));
+
+    // `first` is kept alive, but no longer holds the slot.
+    let _first = first.await.unwrap();
+    second.await.unwrap().unwrap();
+    assert_eq!(
+        calls.lock().unwrap()[1],
+        ("http://a.test/2".to_owned(), secs(1))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_slot_is_kept_while_inner_is_not_ready() {
+    let (service, calls) = recording();
+    let stall = Arc::new(AtomicBool::new(false));
+    let mut service = ConcurrencyLimitLayer::new(1).layer(Stalling {
+        inner: service,
+        stall: stall.clone(),
+    });
+
+    let first = tokio::spawn(service.ready().await.unwrap().call(get("http://a.test/1")));
+    let second = tokio::spawn(service.ready().await.unwrap().call(get("http://a.test/2")));
+
+    // `second` gets the slot, then finds the inner service not ready.
+    stall.store(true, Ordering::SeqCst);
+    first.await.unwrap().unwrap();
+    second.await.unwrap().unwrap();
+    assert_eq!(calls.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_client_reads_body_after_slot_is_released() {
+    let server = server::http(|_| async { http::Response::new(wreq::Body::from("hello")) });
+    let client = Client::builder()
+        .layer(ConcurrencyLimitLayer::new(1))
+        .build()
+        .unwrap();
+    let url = format!("http://{}/", server.addr());
+
+    // The first body is still unread when the second request goes out.
+    let first = client.get(&url).send().await.unwrap();
+    let second = client.get(&url).send().await.unwrap();
+    assert_eq!(second.text().await.unwrap(), "hello");
+    assert_eq!(first.text().await.unwrap(), "hello");
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_custom_keys() {
+    // Clones of the layer share one global limit.
+    let (service, calls) = recording();
+    let layer = ConcurrencyLimitLayer::new(1).key(Global);
+    let mut first = layer.layer(service.clone());
+    let mut second = layer.layer(service);
+
+    tokio::join!(
+        send_all(&mut first, &["http://a.test/"]),
+        send_all(&mut second, &["http://b.test/"]),
+    );
+    let times: Vec<_> = calls.lock().unwrap().iter().map(|call| call.1).collect();
+    assert_eq!(times, [secs(0), secs(1)]);
+
+    // Requests without a key are not limited.
+    let (service, calls) = recording();
+    let mut service = ConcurrencyLimitLayer::new(1)
+        .key(|req: &Request<()>| req.uri().path().starts_with("/api").then(String::new))
+        .layer(service);
+
+    send_all(
+        &mut service,
+        &[
+            "http://a.test/static/1",
+            "http://a.test/api/1",
+            "http://a.test/static/2",
+            "http://a.test/api/2",
+        ],
+    )
+    .await;
+
+    let calls = calls.lock().unwrap();
+    let delayed: Vec<_> = calls.iter().filter(|call| !call.1.is_zero()).collect();
+    assert_eq!(delayed, [&("http://a.test/api/2".to_owned(), secs(1))]);
+}
@@ -0,0 +1,220 @@
+#![cfg(not(target_arch = "wasm32"))]
+mod support;
+
+use http::HeaderValue;
+use support::server;
+use wreq::Client;
+use wreq_util::tower::cookie::{CookieJar, CookieManagerLayer, CookieProvider, CookieStyle};
+
+#[tokio::test]
+async fn test_cookie_manager_round_trip() {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/set" {
+            return http::Response::builder()
+                .header("set-cookie", "a=1; Path=/")
+                .header("set-cookie", "b=2; Path=/get")
+                .header("set-cookie", "c=3; Path=/; Max-Age=0")
+                .body(wreq::Body::default())
+                .unwrap();
+        }
+
+        let cookies = req
+            .headers()
+            .get_all("cookie")
+            .iter()
+            .map(|value| value.to_str().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(cookies, ["b=2; a=1"]);
+        http::Response::default()
+    });
+
+    let jar = CookieJar::new();
+    let client = Client::builder()
+        .layer(CookieManagerLayer::with_jar(jar.clone()))
+        .build()
+        .expect("Unable to build client");
+
+    let res = client
+        .get(format!("http://{}/set", server.addr()))
+        .send()
+        .await
+        .expect("request");
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+
+    let uri = format!("http://{}/get", server.addr()).parse().unwrap();
+    assert_eq!(jar.cookies(&uri), ["b=2", "a=1"]);
+
+    let res = client
+        .get(format!("http://{}/get", server.addr()))
+        .send()
+        .await
+        .expect("request");
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_cookie_manager_keeps_explicit_cookie() {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/set" {
+            return http::Response::builder()
+                .header("set-cookie", "a=1")
+                .body(wreq::Body::default())
+                .unwrap();
+        }
+
+        assert_eq!(req.headers()["cookie"], "manual=1");
+        http::Response::default()
+    });
+
+    let client = Client::builder()
+        .layer(CookieManagerLayer::new())
+        .build()
+        .expect("Unable to build client");
+
+    client
+        .get(format!("http://{}/set", server.addr()))
+        .send()
+        .await
+        .expect("request");
+
+    let res = client
+        .get(format!("http://{}/get", server.addr()))
+        .header("cookie", "manual=1")
+        .send()
+        .await
+        .expect("request");
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_cookie_manager_joins_over_http2() {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/set" {
+            return http::Response::builder()
+                .header("set-cookie", "a=1")
+                .header("set-cookie", "b=2")
+                .body(wreq::Body::default())
+                .unwrap();
+        }
+
+        assert_eq!(req.version(), http::Version::HTTP_2);
+        let cookies = req
+            .headers()
+            .get_all("cookie")
+            .iter()
+            .map(|value| value.to_str().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(cookies, ["a=1; b=2"]);
+        http::Response::default()
+    });
+
+    let client = Client::builder()
+        .http2_only()
+        .layer(CookieManagerLayer::new())
+        .build()
+        .expect("Unable to build client");
+
+    client
+        .get(format!("http://{}/set", server.addr()))
+        .send()
+        .await
+        .expect("request");
+
+    let res = client
+        .get(format!("http://{}/get", server.addr()))
+        .send()
+        .await
+        .expect("request");
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+}
+
+/// Send two stored cookies through a [`CookieProvider`] and return the
+/// `cookie` fields the server received.
+async fn provider_cookie_fields(style: CookieStyle, http2: bool) -> Vec<String> {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/set" {
+            return http::Response::builder()
+                .header("set-cookie", "a=1")
+                .header("set-cookie", "b=2")
+                .body(wreq::Body::default())
+                .unwrap();
+        }
+
+        let cookies = req
+            .headers()
+            .get_all("cookie")
+            .iter()
+            .map(|value| value.to_str().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        http::Response::new(wreq::Body::from(cookies.join("\n")))
+    });
+
+    let builder = Client::builder().cookie_provider(CookieProvider::new(style));
+    let client = if http2 {
+        builder.http2_only()
+    } else {
+        builder.http1_only()
+    }
+    .build()
+    .expect("Unable to build client");
+
+    client
+        .get(format!("http://{}/set", server.addr()))
+        .send()
+        .await
+        .expect("request");
+
+    let res = client
+        .get(format!("http://{}/get", server.addr()))
+        .send()
+        .await
+        .expect("request");
+    let body = res.text().await.expect("text");
+    body.lines().map(str::to_owned).collect()
+}
+
+#[tokio::test]
+async fn test_cookie_provider_styles() {
+    assert_eq!(
+        provider_cookie_fields(CookieStyle::Chrome, true).await,
+        ["a=1", "b=2"]
+    );
+    assert_eq!(
+        provider_cookie_fields(CookieStyle::Chrome, false).await,
+        ["a=1; b=2"]
+    );
+    assert_eq!(
+        provider_cookie_fields(CookieStyle::Firefox, true).await,
+        ["a=1; b=2"]
+    );
+}
+
+#[test]
+fn test_cookie_jar_rejects_public_suffix_domain() {
+    let jar = CookieJar::new();
+    let uri = "https://www.example.com/".parse().unwrap();
+    jar.store(&uri, &HeaderValue::from_static("a=1; Domain=com"));
+    jar.store(&uri, &HeaderValue::from_static("b=2; Domain=.com"));
+    jar.store(&uri, &HeaderValue::from_static("c=3; Domain=other.com"));
+    jar.store(&uri, &HeaderValue::from_static("d=4; Domain=example.com"));
+    assert_eq!(jar.cookies(&uri), ["d=4"]);
+
+    let other = "https://other.com/".parse().unwrap();
+    assert!(jar.cookies(&other).is_empty());
+
+    let local = "https://localhost/".parse().unwrap();
+    jar.store(&local, &HeaderValue::from_static("e=5; Domain=localhost"));
+    assert_eq!(jar.cookies(&local), ["e=5"]);
+}
+
+#[test]
+fn test_cookie_jar_rejects_secure_from_insecure_origin() {
+    let jar = CookieJar::new();
+    let http = "http://example.com/".parse().unwrap();
+    let https = "https://example.com/".parse().unwrap();
+    jar.store(&http, &HeaderValue::from_static("a=1; Secure"));
+    jar.store(&http, &HeaderValue::from_static("b=2"));
+    jar.store(&https, &HeaderValue::from_static("c=3; Secure"));
+    assert_eq!(jar.cookies(&https), ["b=2", "c=3"]);
+    assert_eq!(jar.cookies(&http), ["b=2"]);
+}
@@ -0,0 +1,443 @@
+use std::{
+    convert::Infallible,
+    future::{Ready, ready},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+use tower::{Layer, Service, ServiceExt, service_fn};
+use wreq_util::{
+    Rng,
+    tower::delay::{
+        DelayLayer, DelayPolicy, Distribution, JitterDelayLayer, PolicyDelayLayer, ThinkTimeLayer,
+        policy::{DelayPolicyExt, async_policy, jitter, when},
+    },
+};
+
+async fn echo(req: &'static str) -> Result<&'static str, Infallible> {
+    Ok(req)
+}
+
+type Calls = Arc<Mutex<Vec<(&'static str, Instant)>>>;
+
+/// A mock service that records when each request is dispatched to it.
+fn recording() -> (
+    impl Service<&'static str, Response = &'static str, Error = Infallible> + Clone,
+    Calls,
+) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let service = service_fn({
+        let calls = calls.clone();
+        move |req: &'static str| {
+            calls.lock().unwrap().push((req, Instant::now()));
+            echo(req)
+        }
+    });
+    (service, calls)
+}
+
+/// A mock service that is not `Clone`.
+struct Unique;
+
+impl Service<&'static str> for Unique {
+    type Response = &'static str;
+    type Error = Infallible;
+    type Future = Ready<Result<&'static str, Infallible>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: &'static str) -> Self::Future {
+        ready(Ok(req))
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_delay_with_interleaved_requests() {
+    let mut service = DelayLayer::new(Duration::from_secs(1))
+        .when(|req: &&'static str| *req == "slow")
+        .layer(service_fn(echo));
+
+    for (req, expected) in [
+        ("slow", Duration::from_secs(1)),
+        ("fast", Duration::ZERO),
+        ("slow", Duration::from_secs(1)),
+        ("fast", Duration::ZERO),
+        ("fast", Duration::ZERO),
+        ("slow", Duration::from_secs(1)),
+    ] {
+        let start = Instant::now();
+        let res = service.ready().await.unwrap().call(req).await.unwrap();
+        assert_eq!(res, req);
+        assert_eq!(start.elapsed(), expected, "request {req}");
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_delay_with_cloned_service() {
+    let mut service = DelayLayer::new(Duration::from_secs(1))
+        .when(|req: &&'static str| *req == "slow")
+        .layer(service_fn(echo));
+    let mut clone = service.clone();
+
+    service.ready().await.unwrap().call("fast").await.unwrap();
+
+    let start = Instant::now();
+    clone.ready().await.unwrap().call("slow").await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+    let start = Instant::now();
+    service.ready().await.unwrap().call("slow").await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_jitter_delay_with_interleaved_requests() {
+    let mut service = JitterDelayLayer::new(Duration::from_secs(1), 0.2)
+        .when(|req: &&'static str| *req == "slow")
+        .layer(service_fn(echo));
+
+    for req in ["slow", "fast", "slow", "fast", "fast", "slow"] {
+        let start = Instant::now();
+        service.ready().await.unwrap().call(req).await.unwrap();
+        let elapsed = start.elapsed();
+
+        if req == "slow" {
+            assert!(
+                (Duration::from_millis(800)..=Duration::from_millis(1200)).contains(&elapsed),
+                "request {req} took {elapsed:?}"
+            );
+        } else {
+            assert_eq!(elapsed, Duration::ZERO, "request {req}");
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_delay_with_non_clone_service() {
+    let mut delay = DelayLayer::new(Duration::from_secs(1)).layer(Unique);
+    let mut jitter = JitterDelayLayer::new(Duration::from_secs(1), 0.2).layer(Unique);
+    let mut think_time =
+        ThinkTimeLayer::new(Distribution::empirical([Duration::from_secs(2)])).layer(Unique);
+
+    let start = Instant::now();
+    delay.ready().await.unwrap().call("req").await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+    let start = Instant::now();
+    jitter.ready().await.unwrap().call("req").await.unwrap();
+    assert!((Duration::from_millis(800)..=Duration::from_millis(1200)).contains(&start.elapsed()));
+
+    let start = Instant::now();
+    think_time.ready().await.unwrap().call("req").await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(2));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_policy_delay_dispatches_after_sleep() {
+    let (inner, calls) = recording();
+    let mut service = PolicyDelayLayer::new(Duration::from_secs(1)).layer(inner);
+
+    let start = Instant::now();
+    let response = service.ready().await.unwrap().call("req");
+
+    let (response, _) = tokio::join!(response, async {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert!(calls.lock().unwrap().is_empty());
+    });
+    assert_eq!(response.unwrap(), "req");
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].1 - start, Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_policy_jitter_dispatches_after_sleep() {
+    let (inner, calls) = recording();
+    let mut service = PolicyDelayLayer::new(jitter(Duration::from_secs(1), 0.2)).layer(inner);
+
+    let start = Instant::now();
+    let response = service.ready().await.unwrap().call("req");
+
+    let (response, _) = tokio::join!(response, async {
+        tokio::time::sleep(Duration::from_millis(700)).await;
+        assert!(calls.lock().unwrap().is_empty());
+    });
+    response.unwrap();
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert!(
+        (Duration::from_millis(800)..=Duration::from_millis(1200)).contains(&(calls[0].1 - start))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_policy_delay_dispatches_unmatched_immediately() {
+    let (inner, calls) = recording();
+    let policy = when(|req: &&'static str| *req == "slow").and(Duration::from_secs(1));
+    let mut service = PolicyDelayLayer::new(policy).layer(inner);
+
+    let start = Instant::now();
+    let slow = service.ready().await.unwrap().call("slow");
+    let fast = service.ready().await.unwrap().call("fast");
+
+    assert_eq!(calls.lock().unwrap().as_slice(), [("fast", start)]);
+    let (slow, fast) = tokio::join!(slow, fast);
+    slow.unwrap();
+    fast.unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls[1], ("slow", start + Duration::from_secs(1)));
+}
+
+fn mean_secs(distribution: &Distribution, n: u32) -> f64 {
+    (0..n)
+        .map(|_| distribution.sample().as_secs_f64())
+        .sum::<f64>()
+        / f64::from(n)
+}
+
+#[test]
+fn test_distributions_are_clamped() {
+    let (min, max) = (Duration::from_millis(500), Duration::from_secs(3));
+    for distribution in [
+        Distribution::uniform(Duration::ZERO, Duration::from_secs(10)),
+        Distribution::normal(Duration::from_secs(1), Duration::from_secs(2)),
+        Distribution::log_normal(Duration::from_secs(1), 1.5),
+        Distribution::exponential(Duration::from_secs(1)),
+        Distribution::empirical([Duration::ZERO, Duration::from_secs(60)]),
+    ] {
+        let distribution = distribution.min(min).max(max);
+        for _ in 0..1000 {
+            let delay = distribution.sample();
+            assert!(
+                (min..=max).contains(&delay),
+                "{distribution:?} sampled {delay:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_distribution_shapes() {
+    let mean = mean_secs(
+        &Distribution::uniform(Duration::from_secs(1), Duration::from_secs(3)),
+        20_000,
+    );
+    assert!((mean - 2.0).abs() < 0.1, "uniform mean {mean}");
+
+    let mean = mean_secs(
+        &Distribution::normal(Duration::from_secs(5), Duration::from_secs(1)),
+        20_000,
+    );
+    assert!((mean - 5.0).abs() < 0.1, "normal mean {mean}");
+
+    let mean = mean_secs(&Distribution::exponential(Duration::from_secs(2)), 20_000);
+    assert!((mean - 2.0).abs() < 0.15, "exponential mean {mean}");
+
+    // Half of the log-normal samples fall below the median, with a long tail above.
+    let distribution = Distribution::log_normal(Duration::from_secs(2), 1.0);
+    let samples: Vec<_> = (0..20_000).map(|_| distribution.sample()).collect();
+    let below = samples
+        .iter()
+        .filter(|d| **d < Duration::from_secs(2))
+        .count();
+    assert!(
+        (9_000..=11_000).contains(&below),
+        "log-normal median split {below}"
+    );
+    assert!(samples.iter().any(|d| *d > Duration::from_secs(10)));
+
+    // Empirical samples stay within the recorded range.
+    let recorded = [1, 2, 2, 3, 8].map(Duration::from_secs);
+    let distribution = Distribution::empirical(recorded);
+    for _ in 0..1000 {
+        let delay = distribution.sample();
+        assert!((recorded[0]..=recorded[4]).contains(&delay));
+    }
+    assert_eq!(
+        Distribution::empirical([Duration::from_secs(4)]).sample(),
+        Duration::from_secs(4)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_think_time_with_interleaved_requests() {
+    let (inner, calls) = recording();
+    let mut service = ThinkTimeLayer::new(Distribution::empirical([Duration::from_secs(2)]))
+        .when(|req: &&'static str| *req == "slow")
+        .layer(inner);
+
+    let start = Instant::now();
+    let slow = service.ready().await.unwrap().call("slow");
+    let fast = service.ready().await.unwrap().call("fast");
+    let (slow, fast) = tokio::join!(slow, fast);
+    slow.unwrap();
+    fast.unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(
+        calls.as_slice(),
+        [("fast", start), ("slow", start + Duration::from_secs(2))]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_seeded_delays_are_reproducible() {
+    async fn delays<S>(mut service: S) -> Vec<Duration>
+    where
+        S: Service<&'static str, Error = tower::BoxError>,
+    {
+        let mut delays = Vec::new();
+        for _ in 0..5 {
+            let start = Instant::now();
+            service.ready().await.unwrap().call("req").await.unwrap();
+            delays.push(start.elapsed());
+        }
+        delays
+    }
+
+    let jitter = || JitterDelayLayer::new(Duration::from_secs(1), 0.5).rng(Rng::seeded(42));
+    let first = delays(jitter().layer(service_fn(echo))).await;
+    assert_eq!(first, delays(jitter().layer(service_fn(echo))).await);
+    assert!(first.windows(2).any(|pair| pair[0] != pair[1]));
+
+    let think_time = || {
+        ThinkTimeLayer::new(Distribution::exponential(Duration::from_secs(1))).rng(Rng::seeded(42))
+    };
+    let first = delays(think_time().layer(service_fn(echo))).await;
+    assert_eq!(first, delays(think_time().layer(service_fn(echo))).await);
+
+    let policy = || {
+        PolicyDelayLayer::new(Distribution::exponential(Duration::from_secs(1)))
+            .rng(Rng::seeded(42))
+    };
+    let first = delays(policy().layer(service_fn(echo))).await;
+    assert_eq!(first, delays(policy().layer(service_fn(echo))).await);
+    assert!(first.windows(2).any(|pair| pair[0] != pair[1]));
+
+    let distribution = Distribution::log_normal(Duration::from_secs(1), 1.0);
+    let (a, b) = (Rng::seeded(1), Rng::seeded(1));
+    for _ in 0..100 {
+        assert_eq!(distribution.sample_with(&a), distribution.sample_with(&b));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_policy_delay_per_request_duration() {
+    let mut service = PolicyDelayLayer::new(|req: &&'static str| match *req {
+        "checkout" => Some(Duration::from_secs(3)),
+        "search" => Some(Duration::from_millis(500)),
+        _ => None,
+    })
+    .layer(service_fn(echo));
+
+    for (req, expected) in [
+        ("checkout", Duration::from_secs(3)),
+        ("search", Duration::from_millis(500)),
+        ("home", Duration::ZERO),
+    ] {
+        let start = Instant::now();
+        service.ready().await.unwrap().call(req).await.unwrap();
+        assert_eq!(start.elapsed(), expected, "request {req}");
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_policy_delay_with_async_shared_state() {
+    // Each request waits one second longer than the previous one, after a
+    // 100ms lookup of the shared state.
+    let sent = Arc::new(AtomicU64::new(0));
+    let policy = async_policy({
+        let sent = sent.clone();
+        move |_: &&'static str| {
+            let sent = sent.fetch_add(1, Ordering::SeqCst);
+            async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Some(Duration::from_secs(sent))
+            }
+        }
+    });
+    let (inner, calls) = recording();
+    let layer = PolicyDelayLayer::new(policy);
+    let mut service = layer.layer(inner.clone());
+    let mut other = layer.layer(inner);
+
+    let start = Instant::now();
+    let first = service.ready().await.unwrap().call("a");
+    let second = other.ready().await.unwrap().call("b");
+    let (first, second) = tokio::join!(first, second);
+    first.unwrap();
+    second.unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(
+        calls.as_slice(),
+        [
+            ("a", start + Duration::from_millis(100)),
+            ("b", start + Duration::from_millis(1100)),
+        ]
+    );
+    assert_eq!(sent.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_policy_combinators() {
+    let api = || when(|req: &&'static str| req.starts_with("/api"));
+    let health = || when(|req: &&'static str| *req == "/api/health");
+    let policy = api()
+        .and(health().not())
+        .and(Duration::from_secs(1))
+        .or(health().and(Duration::from_secs(5)));
+
+    let mut service = PolicyDelayLayer::new(policy).layer(service_fn(echo));
+    for (req, expected) in [
+        ("/api/users", Duration::from_secs(1)),
+        ("/api/health", Duration::from_secs(5)),
+        ("/static/app.js", Duration::ZERO),
+    ] {
+        let start = Instant::now();
+        service.ready().await.unwrap().call(req).await.unwrap();
+        assert_eq!(start.elapsed(), expected, "request {req}");
+    }
+
+    // `and` keeps the longer delay, `or` the first that applies.
+    async fn decide(mut policy: impl DelayPolicy<&'static str>) -> Option<Duration> {
+        policy.delay(&"req", &Rng::default()).await
+    }
+    let always = || when(|_: &&'static str| true);
+    let (one, two) = (Duration::from_secs(1), Duration::from_secs(2));
+    assert_eq!(decide(always().and(two).and(one)).await, Some(two));
+    assert_eq!(decide(always().and(one).or(two)).await, Some(one));
+    assert_eq!(decide(always().not()).await, None);
+    assert_eq!(decide(always().not().or(two)).await, Some(two));
+    assert_eq!(decide(always().not().not()).await, Some(Duration::ZERO));
+
+    // The second policy is only asked when its decision is needed.
+    let asked = Arc::new(AtomicU64::new(0));
+    let counted = || {
+        let asked = asked.clone();
+        move |_: &&'static str| {
+            asked.fetch_add(1, Ordering::SeqCst);
+            Some(one)
+        }
+    };
+    assert_eq!(decide(always().not().and(counted())).await, None);
+    assert_eq!(decide(always().or(counted())).await, Some(Duration::ZERO));
+    assert_eq!(asked.load(Ordering::SeqCst), 0);
+    assert_eq!(decide(always().not().or(counted())).await, Some(one));
+    assert_eq!(decide(always().and(counted())).await, Some(one));
+    assert_eq!(asked.load(Ordering::SeqCst), 2);
+
+    // Synchronous decisions are kept when an asynchronous policy follows.
+    let later = || async_policy(move |_: &&'static str| async move { Some(two) });
+    assert_eq!(decide(always().and(later()).and(one)).await, Some(two));
+    assert_eq!(decide(always().not().or(later()).or(one)).await, Some(two));
+    assert_eq!(decide(always().not().and(later()).or(one)).await, Some(one));
+}
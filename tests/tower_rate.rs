@@ -0,0 +1,178 @@
+use std::{
+    convert::Infallible,
+    future::{Ready, ready},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use http::Request;
+use tokio::time::Instant;
+use tower::{BoxError, Layer, Service, ServiceExt, service_fn};
+use wreq_util::tower::rate::{ByOrigin, RateLimitLayer};
+
+type Calls = Arc<Mutex<Vec<(String, Duration)>>>;
+
+/// A mock service that records when each request is dispatched to it,
+/// relative to the start of the test.
+fn recording() -> (
+    impl Service<Request<()>, Response = (), Error = Infallible, Future = Ready<Result<(), Infallible>>>
+    + Clone
+    + Send
+    + 'static,
+    Calls,
+) {
+    let start = Instant::now();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let service = service_fn({
+        let calls = calls.clone();
+        move |req: Request<()>| {
+            calls
+                .lock()
+                .unwrap()
+                .push((req.uri().to_string(), start.elapsed()));
+            ready(Ok(()))
+        }
+    });
+    (service, calls)
+}
+
+fn get(uri: &str) -> Request<()> {
+    Request::get(uri).body(()).unwrap()
+}
+
+/// Call the service with each request in order, then wait for all of them.
+async fn send_all<S>(service: &mut S, uris: &[&str])
+where
+    S: Service<Request<()>, Response = (), Error = BoxError>,
+    S::Future: Send + 'static,
+{
+    let mut handles = Vec::new();
+    for uri in uris {
+        handles.push(tokio::spawn(service.ready().await.unwrap().call(get(uri))));
+    }
+    for handle in handles {
+        handle.await.unwrap().unwrap();
+    }
+}
+
+fn secs(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_token_bucket_spaces_requests_per_host() {
+    let (service, calls) = recording();
+    let mut service = RateLimitLayer::token_bucket(1, secs(1)).layer(service);
+
+    send_all(
+        &mut service,
+        &[
+            "http://a.test/1",
+            "http://b.test/1",
+            "http://a.test/2",
+            "http://A.test/3",
+            "http://b.test/2",
+        ],
+    )
+    .await;
+
+    let mut calls = calls.lock().unwrap().clone();
+    calls.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    assert_eq!(
+        calls,
+        [
+            ("http://a.test/1".to_owned(), secs(0)),
+            ("http://b.test/1".to_owned(), secs(0)),
+            ("http://a.test/2".to_owned(), secs(1)),
+            ("http://b.test/2".to_owned(), secs(1)),
+            ("http://A.test/3".to_owned(), secs(2)),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_token_bucket_burst_refills_over_time() {
+    let (service, calls) = recording();
+    let layer = RateLimitLayer::token_bucket(2, secs(1)).burst(3);
+    let mut service = layer.layer(service);
+
+    let uris = ["http://a.test/"; 5];
+    send_all(&mut service, &uris).await;
+
+    let times: Vec<_> = calls.lock().unwrap().iter().map(|call| call.1).collect();
+    let ms = Duration::from_millis;
+    assert_eq!(times, [ms(0), ms(0), ms(0), ms(500), ms(1000)]);
+
+    // An idle host gets its burst back, and clones share the same limit.
+    tokio::time::sleep(secs(10)).await;
+    calls.lock().unwrap().clear();
+    let mut clone = layer.layer(recording().0);
+    send_all(&mut clone, &["http://a.test/"; 3]).await;
+    let start = Instant::now();
+    send_all(&mut service, &["http://a.test/"]).await;
+    assert_eq!(start.elapsed(), ms(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_sliding_window() {
+    let (service, calls) = recording();
+    let mut service = RateLimitLayer::sliding_window(2, secs(10)).layer(service);
+
+    send_all(&mut service, &["http://a.test/"; 2]).await;
+    tokio::time::sleep(secs(4)).await;
+    send_all(&mut service, &["http://a.test/"; 3]).await;
+
+    let times: Vec<_> = calls.lock().unwrap().iter().map(|call| call.1).collect();
+    assert_eq!(times, [secs(0), secs(0), secs(10), secs(10), secs(20)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_custom_keys() {
+    let (service, calls) = recording();
+    let mut service = RateLimitLayer::token_bucket(1, secs(1))
+        .key(ByOrigin)
+        .layer(service);
+
+    send_all(
+        &mut service,
+        &["http://a.test/", "https://a.test/", "http://a.test:8080/"],
+    )
+    .await;
+    assert!(calls.lock().unwrap().iter().all(|call| call.1.is_zero()));
+
+    // Requests without a key pass through unlimited.
+    let (service, calls) = recording();
+    let mut service = RateLimitLayer::token_bucket(1, secs(1))
+        .key(|req: &Request<()>| req.uri().path().starts_with("/api").then(String::new))
+        .layer(service);
+
+    send_all(
+        &mut service,
+        &[
+            "http://a.test/api/1",
+            "http://a.test/static",
+            "http://b.test/api/2",
+            "http://b.test/static",
+        ],
+    )
+    .await;
+
+    let calls = calls.lock().unwrap();
+    let delayed: Vec<_> = calls.iter().filter(|call| !call.1.is_zero()).collect();
+    assert_eq!(delayed, [&("http://b.test/api/2".to_owned(), secs(1))]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_jitter_only_delays_held_back_requests() {
+    let (service, calls) = recording();
+    let mut service = RateLimitLayer::token_bucket(1, secs(1))
+        .jitter(0.5)
+        .layer(service);
+
+    send_all(&mut service, &["http://a.test/"; 3]).await;
+
+    let times: Vec<_> = calls.lock().unwrap().iter().map(|call| call.1).collect();
+    assert_eq!(times[0], secs(0));
+    assert!((secs(1)..=Duration::from_millis(1500)).contains(&times[1]));
+    assert!((secs(2)..=Duration::from_millis(2500)).contains(&times[2]));
+}
@@ -0,0 +1,174 @@
+#![cfg(not(target_arch = "wasm32"))]
+mod support;
+
+use std::{
+    convert::Infallible,
+    future::poll_fn,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use http_body::{Body, Frame};
+use support::server;
+use wreq::Client;
+use wreq_util::tower::redirect::{BrowserRedirectLayer, TooManyRedirects};
+
+#[tokio::test]
+async fn test_browser_redirect_see_other() {
+    let server = server::http(move |req| async move {
+        match req.uri().path() {
+            "/form" => {
+                assert_eq!(req.method(), "POST");
+                http::Response::builder()
+                    .status(303)
+                    .header("location", "./done?ok=1")
+                    .body(wreq::Body::default())
+                    .unwrap()
+            }
+            "/done" => {
+                assert_eq!(req.method(), "GET");
+                assert_eq!(req.uri().query(), Some("ok=1"));
+                assert!(req.headers().get("content-type").is_none());
+                assert!(req.headers().get("origin").is_none());
+                assert_eq!(req.headers()["referer"], "http://example.com/");
+                assert_eq!(req.headers()["authorization"], "Bearer token");
+                http::Response::default()
+            }
+            _ => unreachable!(),
+        }
+    });
+
+    let client = Client::builder()
+        .layer(BrowserRedirectLayer::new())
+        .build()
+        .expect("Unable to build client");
+
+    let res = client
+        .post(format!("http://{}/form", server.addr()))
+        .header("content-type", "application/x-www-form-urlencoded")
+        .header("origin", "http://example.com")
+        .header("referer", "http://example.com/page")
+        .header("authorization", "Bearer token")
+        .body("a=1")
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert_eq!(res.uri().path(), "/done");
+}
+
+/// A body of unknown length, like a streamed upload.
+struct Streamed(Option<&'static [u8]>);
+
+impl Body for Streamed {
+    type Data = &'static [u8];
+    type Error = Infallible;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        Poll::Ready(self.0.take().map(|chunk| Ok(Frame::data(chunk))))
+    }
+}
+
+async fn read_body(mut body: hyper::body::Incoming) -> Vec<u8> {
+    let mut data = Vec::new();
+    while let Some(frame) = poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+        if let Ok(chunk) = frame.unwrap().into_data() {
+            data.extend_from_slice(&chunk);
+        }
+    }
+    data
+}
+
+#[tokio::test]
+async fn test_browser_redirect_replays_body() {
+    let server = server::http(move |req| async move {
+        if req.uri().path() == "/upload" {
+            return http::Response::builder()
+                .status(307)
+                .header("location", "/elsewhere")
+                .body(wreq::Body::default())
+                .unwrap();
+        }
+
+        let method = req.method().clone();
+        let body = read_body(req.into_body()).await;
+        let echo = format!("{method} {}", String::from_utf8(body).unwrap());
+        http::Response::new(wreq::Body::from(echo))
+    });
+
+    let client = Client::builder()
+        .layer(BrowserRedirectLayer::new())
+        .build()
+        .expect("Unable to build client");
+
+    let res = client
+        .put(format!("http://{}/upload", server.addr()))
+        .body("data")
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.uri().path(), "/elsewhere");
+    assert_eq!(res.text().await.expect("text"), "PUT data");
+}
+
+#[tokio::test]
+async fn test_browser_redirect_without_replayable_body() {
+    let server = server::http(move |req| async move {
+        read_body(req.into_body()).await;
+        http::Response::builder()
+            .status(307)
+            .header("location", "/elsewhere")
+            .body(wreq::Body::default())
+            .unwrap()
+    });
+
+    let client = Client::builder()
+        .layer(BrowserRedirectLayer::new())
+        .build()
+        .expect("Unable to build client");
+
+    let res = client
+        .put(format!("http://{}/upload", server.addr()))
+        .body(wreq::Body::wrap(Streamed(Some(b"data"))))
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::TEMPORARY_REDIRECT);
+}
+
+#[tokio::test]
+async fn test_browser_redirect_too_many() {
+    let server = server::http(move |_req| async move {
+        http::Response::builder()
+            .status(302)
+            .header("location", "/loop")
+            .body(wreq::Body::default())
+            .unwrap()
+    });
+
+    let client = Client::builder()
+        .layer(BrowserRedirectLayer::new().max_redirects(3))
+        .build()
+        .expect("Unable to build client");
+
+    let err = client
+        .get(format!("http://{}/loop", server.addr()))
+        .send()
+        .await
+        .unwrap_err();
+
+    let mut source = std::error::Error::source(&err);
+    while let Some(err) = source {
+        if err.is::<TooManyRedirects>() {
+            return;
+        }
+        source = err.source();
+    }
+    panic!("expected TooManyRedirects, got {err:?}");
+}
@@ -0,0 +1,258 @@
+#![cfg(not(target_arch = "wasm32"))]
+mod support;
+
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use http::{HeaderValue, Method, Request, Response, StatusCode};
+use http_body::Body;
+use support::server;
+use tokio::time::Instant;
+use tower::{BoxError, Layer, Service, ServiceExt, service_fn};
+use wreq::Client;
+use wreq_util::tower::retry::{RetryBudget, RetryLayer};
+
+type Outcome = Result<StatusCode, io::ErrorKind>;
+
+type Seen = Arc<Mutex<Vec<Request<wreq::Body>>>>;
+
+/// A mock service that answers with the given outcomes in order, and `200 OK`
+/// once they run out. Every request it receives is recorded.
+fn scripted(
+    outcomes: impl IntoIterator<Item = Outcome>,
+) -> (
+    impl Service<Request<wreq::Body>, Response = Response<()>, Error = BoxError> + Clone,
+    Seen,
+) {
+    let outcomes = Arc::new(Mutex::new(outcomes.into_iter().collect::<VecDeque<_>>()));
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let service = service_fn({
+        let seen = seen.clone();
+        move |req: Request<wreq::Body>| {
+            seen.lock().unwrap().push(req);
+            let outcome = outcomes.lock().unwrap().pop_front();
+            async move {
+                match outcome.unwrap_or(Ok(StatusCode::OK)) {
+                    Ok(status) => Ok(Response::builder().status(status).body(()).unwrap()),
+                    Err(kind) => Err(BoxError::from(io::Error::from(kind))),
+                }
+            }
+        }
+    });
+    (service, seen)
+}
+
+fn request(method: Method, body: &'static str) -> Request<wreq::Body> {
+    Request::builder()
+        .method(method)
+        .uri("http://a.test/")
+        .body(wreq::Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_statuses_with_backoff() {
+    let (service, seen) = scripted([
+        Ok(StatusCode::SERVICE_UNAVAILABLE),
+        Ok(StatusCode::BAD_GATEWAY),
+    ]);
+    let mut service = RetryLayer::new()
+        .backoff(Duration::from_secs(1), Duration::from_secs(10))
+        .layer(service);
+
+    let start = Instant::now();
+    let res = service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(Method::GET, ""))
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(seen.lock().unwrap().len(), 3);
+    // Full jitter waits up to 1s, then up to 2s.
+    assert!(start.elapsed() <= Duration::from_secs(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_gives_up_after_max_retries() {
+    let (service, seen) = scripted([Ok(StatusCode::SERVICE_UNAVAILABLE); 5]);
+    let mut service = RetryLayer::new().max_retries(2).layer(service);
+
+    let res = service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(Method::GET, ""))
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(seen.lock().unwrap().len(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_respects_idempotency() {
+    // A non-idempotent request may have reached the server.
+    let (service, seen) = scripted([Ok(StatusCode::SERVICE_UNAVAILABLE)]);
+    let mut service = RetryLayer::new().layer(service);
+    let res = service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(Method::POST, ""))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(seen.lock().unwrap().len(), 1);
+
+    let (service, seen) = scripted([Err(io::ErrorKind::ConnectionReset)]);
+    let mut service = RetryLayer::new().layer(service);
+    let res = service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(Method::POST, ""))
+        .await;
+    assert!(res.is_err());
+    assert_eq!(seen.lock().unwrap().len(), 1);
+
+    // A refused connection never sent anything, so any method is safe.
+    let (service, seen) = scripted([Err(io::ErrorKind::ConnectionRefused)]);
+    let mut service = RetryLayer::new().layer(service);
+    let res = service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(Method::POST, ""))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(seen.lock().unwrap().len(), 2);
+
+    // Bodies of a known size are buffered and sent again.
+    let (service, seen) = scripted([Err(io::ErrorKind::ConnectionRefused)]);
+    let mut service = RetryLayer::new().layer(service);
+    let res = service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(Method::PUT, "data"))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    assert_eq!(seen[1].body().size_hint().exact(), Some(4));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Profile(&'static str);
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_keeps_request_and_rotates_identity() {
+    let (service, seen) = scripted([
+        Err(io::ErrorKind::TimedOut),
+        Ok(StatusCode::TOO_MANY_REQUESTS),
+    ]);
+    let mut service = RetryLayer::new()
+        .on_retry(|parts, attempt| {
+            let value = HeaderValue::from_str(&format!("identity-{attempt}")).unwrap();
+            parts.headers.insert("x-identity", value);
+        })
+        .layer(service);
+
+    let mut req = request(Method::GET, "");
+    req.headers_mut()
+        .insert("user-agent", HeaderValue::from_static("test-agent"));
+    req.extensions_mut().insert(Profile("chrome"));
+    service.ready().await.unwrap().call(req).await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 3);
+    for (attempt, req) in seen.iter().enumerate() {
+        assert_eq!(req.headers()["user-agent"], "test-agent");
+        assert_eq!(req.extensions().get(), Some(&Profile("chrome")));
+        let identity = req.headers().get("x-identity");
+        match attempt {
+            0 => assert!(identity.is_none()),
+            n => assert_eq!(identity.unwrap(), format!("identity-{n}").as_str()),
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_budget() {
+    let (service, seen) = scripted([Ok(StatusCode::SERVICE_UNAVAILABLE); 10]);
+    let mut service = RetryLayer::new()
+        .budget(RetryBudget::new(0.0, 1))
+        .layer(service);
+
+    for _ in 0..2 {
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call(request(Method::GET, ""))
+            .await
+            .unwrap();
+    }
+    // One retry for the first request, then the budget is spent.
+    assert_eq!(seen.lock().unwrap().len(), 3);
+
+    // The reserve refills over time.
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call(request(Method::GET, ""))
+        .await
+        .unwrap();
+    assert_eq!(seen.lock().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn test_retry_with_client() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let server = server::http({
+        let hits = hits.clone();
+        move |req| {
+            let hits = hits.clone();
+            async move {
+                assert_eq!(req.headers()["user-agent"], "retry-test");
+                if hits.fetch_add(1, Ordering::SeqCst) < 2 {
+                    http::Response::builder()
+                        .status(503)
+                        .body(wreq::Body::default())
+                        .unwrap()
+                } else {
+                    http::Response::default()
+                }
+            }
+        }
+    });
+
+    let client = Client::builder()
+        .layer(RetryLayer::new().backoff(Duration::from_millis(10), Duration::from_millis(50)))
+        .build()
+        .expect("Unable to build client");
+
+    let res = client
+        .get(format!("http://{}/", server.addr()))
+        .header("user-agent", "retry-test")
+        .send()
+        .await
+        .expect("request");
+
+    assert_eq!(res.status(), wreq::StatusCode::OK);
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,118 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{
+    convert::Infallible,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use tower::{Layer, Service, ServiceExt, service_fn};
+use wreq_util::tower::{
+    delay::{DelayLayer, JitterDelayLayer},
+    timer::{Sleep, Timer, TokioTimer},
+};
+
+async fn echo(req: &'static str) -> Result<&'static str, Infallible> {
+    Ok(req)
+}
+
+/// A timer that counts the sleeps it hands out.
+#[derive(Clone, Default)]
+struct CountingTimer(Arc<AtomicUsize>);
+
+impl Timer for CountingTimer {
+    fn sleep(&self, duration: Duration) -> Sleep {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        TokioTimer::new().sleep(duration)
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_tokio_timer_delays() {
+    let mut delay = DelayLayer::new(Duration::from_secs(1))
+        .timer(TokioTimer::new())
+        .layer(service_fn(echo));
+    let mut jitter = JitterDelayLayer::new(Duration::from_secs(1), 0.2)
+        .timer(TokioTimer::new())
+        .layer(service_fn(echo));
+
+    let start = tokio::time::Instant::now();
+    delay.ready().await.unwrap().call("req").await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+    let start = tokio::time::Instant::now();
+    jitter.ready().await.unwrap().call("req").await.unwrap();
+    assert!((Duration::from_millis(800)..=Duration::from_millis(1200)).contains(&start.elapsed()));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_tokio_timer_follows_paused_clock() {
+    let timer = TokioTimer::new();
+    let start = timer.now();
+    tokio::time::advance(Duration::from_secs(5)).await;
+    assert_eq!(timer.now() - start, Duration::from_secs(5));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_custom_timer_is_shared_by_services() {
+    let timer = CountingTimer::default();
+    let layer = DelayLayer::new(Duration::from_secs(1))
+        .when(|req: &&'static str| *req == "slow")
+        .timer(timer.clone());
+    let mut service = layer.layer(service_fn(echo));
+    let mut clone = layer.layer(service_fn(echo));
+
+    for req in ["slow", "fast", "slow"] {
+        service.ready().await.unwrap().call(req).await.unwrap();
+    }
+    clone.ready().await.unwrap().call("slow").await.unwrap();
+
+    assert_eq!(timer.0.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(feature = "compio-rt")]
+#[test]
+fn test_compio_timer_delays() {
+    use std::time::Instant;
+
+    use wreq_util::tower::timer::CompioTimer;
+
+    let runtime = compio::runtime::Runtime::new().unwrap();
+    runtime.block_on(async {
+        let mut delay = DelayLayer::new(Duration::from_millis(100))
+            .timer(CompioTimer::new())
+            .layer(service_fn(echo));
+        let mut jitter = JitterDelayLayer::new(Duration::from_millis(100), 0.2)
+            .timer(CompioTimer::new())
+            .layer(service_fn(echo));
+
+        let start = Instant::now();
+        assert_eq!(
+            delay.ready().await.unwrap().call("req").await.unwrap(),
+            "req"
+        );
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        let start = Instant::now();
+        jitter.ready().await.unwrap().call("req").await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(80));
+    });
+}
+
+#[cfg(feature = "compio-rt")]
+#[test]
+fn test_compio_is_default_timer() {
+    use std::time::Instant;
+
+    let runtime = compio::runtime::Runtime::new().unwrap();
+    runtime.block_on(async {
+        let mut service = DelayLayer::new(Duration::from_millis(50)).layer(service_fn(echo));
+
+        let start = Instant::now();
+        service.ready().await.unwrap().call("req").await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    });
+}